@@ -0,0 +1,55 @@
+//! Benchmarks for the DHT11 decode and debounce hot paths
+//!
+//! Run with `cargo bench`; a single group can be selected with e.g.
+//! `cargo bench -- decode`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use env_monitor::sensors::debounce::DebouncedInput;
+use env_monitor::sensors::dht11::{Dht11Capture, decode_capture, majority_vote};
+use rppal::gpio::Level;
+use std::hint::black_box;
+
+// Bit timings for the frame 45% / 23°C: [45, 0, 23, 0, 68]
+fn sample_capture() -> Dht11Capture {
+    let frame: [u8; 5] = [45, 0, 23, 0, 68];
+    Dht11Capture {
+        high_durations_us: frame
+            .iter()
+            .flat_map(|byte| {
+                (0..8)
+                    .rev()
+                    .map(move |bit| if byte >> bit & 1 == 1 { 70 } else { 27 })
+            })
+            .collect(),
+    }
+}
+
+fn decode(c: &mut Criterion) {
+    let capture = sample_capture();
+    c.bench_function("decode_capture", |b| {
+        b.iter(|| decode_capture(black_box(&capture)))
+    });
+
+    let frames = [[45, 0, 23, 0, 68], [45, 0, 27, 0, 68], [45, 0, 23, 0, 68]];
+    c.bench_function("majority_vote_3_frames", |b| {
+        b.iter(|| majority_vote(black_box(&frames)))
+    });
+}
+
+fn debounce(c: &mut Criterion) {
+    let levels: Vec<Level> = (0..1000)
+        .map(|i| if i % 7 < 4 { Level::High } else { Level::Low })
+        .collect();
+
+    c.bench_function("debounce_1000_samples", |b| {
+        b.iter(|| {
+            let mut input = DebouncedInput::new(true, 3);
+            for level in &levels {
+                black_box(input.update(*level));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, decode, debounce);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+//! Benchmark of one fire monitoring loop iteration against a mock GPIO
+//!
+//! Run with `cargo bench --features mock --bench monitoring`.
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use env_monitor::sensors::debounce::DebouncedInput;
+use env_monitor::sensors::fire::Pull;
+use env_monitor::sensors::mock::{MockGpio, MockLine};
+use rppal::gpio::Level;
+use std::hint::black_box;
+
+// Flame input and buzzer output claimed on a fresh controller, as monitoring holds them
+fn claimed_pins() -> (MockLine, MockLine, DebouncedInput) {
+    let gpio = MockGpio::new();
+    gpio.set_level(27, Level::Low);
+    let flame = gpio.claim(27, Pull::None).unwrap();
+    let buzzer = gpio.claim(22, Pull::None).unwrap();
+    (flame, buzzer, DebouncedInput::new(true, 3))
+}
+
+fn monitoring_iteration(c: &mut Criterion) {
+    // Each batch gets fresh pins, so recorded buzzer writes don't pile up
+    c.bench_function("fire_monitoring_iteration", |b| {
+        b.iter_batched_ref(
+            claimed_pins,
+            |(flame, buzzer, input)| {
+                let detected = input.update(flame.read());
+                buzzer.write(if black_box(detected) {
+                    Level::High
+                } else {
+                    Level::Low
+                });
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, monitoring_iteration);
+criterion_main!(benches);
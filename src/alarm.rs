@@ -0,0 +1,330 @@
+//! Alarm arbitration across multiple hazard sensors
+
+use rppal::gpio::Level;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, sleep};
+
+use crate::error::SensorError;
+use crate::sensors::gpio::{GpioHandle, OutputLine};
+
+/// Highest tone frequency a buzzer can be driven at, with a 1µs half period
+///
+/// Tones above it are played at this frequency instead.
+pub const MAX_TONE_FREQUENCY_HZ: u32 = 500_000;
+
+/// Source of an alarm, ordered by priority (lowest first)
+///
+/// When several sources are active at once the arbiter sounds the pattern of
+/// the highest-priority one: fire > gas > temperature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AlarmSource {
+    /// Over-temperature condition
+    Temperature,
+    /// Smoke or gas detection
+    Gas,
+    /// Flame detection
+    Fire,
+}
+
+impl AlarmSource {
+    /// Tone frequency (Hz) and tone duration (ms) sounded for this source
+    pub fn pattern(self) -> (u32, u64) {
+        match self {
+            AlarmSource::Fire => (1000, 200),
+            AlarmSource::Gas => (800, 150),
+            AlarmSource::Temperature => (500, 100),
+        }
+    }
+}
+
+/// Drives a single buzzer on behalf of several hazard sensors
+///
+/// Sensors (or application code reacting to their readings) raise and clear
+/// alarms by source; the arbiter always sounds the highest-priority active one.
+pub struct AlarmArbiter {
+    /// GPIO pin number connected to the buzzer
+    buzzer_pin: u8,
+    /// Currently raised alarm sources
+    active: Arc<Mutex<Vec<AlarmSource>>>,
+    /// Arbiter running state
+    is_running: Arc<AtomicBool>,
+    /// Buzzer logic (true = sounds on high level, false = sounds on low level)
+    buzzer_active_high: bool,
+    /// GPIO controller the buzzer pin is acquired from
+    gpio: GpioHandle,
+}
+
+impl AlarmArbiter {
+    /// Create a new alarm arbiter driving the given buzzer pin
+    ///
+    /// # Arguments
+    /// * `buzzer_pin` - GPIO pin number connected to the buzzer
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::alarm::{AlarmArbiter, AlarmSource};
+    ///
+    /// let arbiter = AlarmArbiter::new(22);
+    /// arbiter.raise(AlarmSource::Temperature);
+    /// arbiter.raise(AlarmSource::Fire);
+    /// arbiter.raise(AlarmSource::Gas);
+    /// assert_eq!(arbiter.active_alarm(), Some(AlarmSource::Fire));
+    ///
+    /// arbiter.clear(AlarmSource::Fire);
+    /// assert_eq!(arbiter.active_alarm(), Some(AlarmSource::Gas));
+    /// ```
+    pub fn new(buzzer_pin: u8) -> Self {
+        AlarmArbiter {
+            buzzer_pin,
+            active: Arc::new(Mutex::new(Vec::new())),
+            is_running: Arc::new(AtomicBool::new(false)),
+            buzzer_active_high: false,
+            gpio: GpioHandle::default(),
+        }
+    }
+
+    /// Acquire the buzzer pin from the given GPIO controller
+    ///
+    /// Accepts an opened `rppal::gpio::Gpio` (or `Arc<Gpio>`) to share one
+    /// controller with the sensors, or a `MockGpio` with the `mock` feature. By
+    /// default the arbiter opens the controller itself on first use.
+    pub fn with_gpio(mut self, gpio: impl Into<GpioHandle>) -> Self {
+        self.gpio = gpio.into();
+        self
+    }
+
+    /// Set the buzzer logic (true if the buzzer sounds on high level)
+    ///
+    /// Defaults to false, matching the common active-low buzzer modules.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::alarm::AlarmArbiter;
+    /// use rppal::gpio::Level;
+    ///
+    /// let arbiter = AlarmArbiter::new(22).with_buzzer_active_high(true);
+    /// assert_eq!(arbiter.buzzer_idle_level(), Level::Low);
+    /// ```
+    pub fn with_buzzer_active_high(mut self, active_high: bool) -> Self {
+        self.buzzer_active_high = active_high;
+        self
+    }
+
+    /// Level the buzzer pin is held at while silent
+    pub fn buzzer_idle_level(&self) -> Level {
+        idle_level(self.buzzer_active_high)
+    }
+
+    /// Raise an alarm from the given source
+    pub fn raise(&self, source: AlarmSource) {
+        let mut active = self.active.lock().unwrap();
+        if !active.contains(&source) {
+            active.push(source);
+        }
+    }
+
+    /// Clear an alarm from the given source
+    pub fn clear(&self, source: AlarmSource) {
+        self.active.lock().unwrap().retain(|s| *s != source);
+    }
+
+    /// Highest-priority alarm currently raised, if any
+    pub fn active_alarm(&self) -> Option<AlarmSource> {
+        self.active.lock().unwrap().iter().max().copied()
+    }
+
+    /// Start driving the buzzer with the highest-priority active pattern
+    ///
+    /// # Arguments
+    /// * `check_interval_ms` - Interval in milliseconds between arbitration checks
+    ///
+    /// # Returns
+    /// Handle of the arbitration task, which exits with the buzzer idle after
+    /// [`stop`](AlarmArbiter::stop)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::alarm::{AlarmArbiter, AlarmSource};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let arbiter = AlarmArbiter::new(22);
+    ///     let handle = arbiter.start(100).await?;
+    ///
+    ///     arbiter.raise(AlarmSource::Gas);
+    ///
+    ///     arbiter.stop();
+    ///     handle.await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Against a `MockGpio` (with the `mock` feature):
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::alarm::{AlarmArbiter, AlarmSource};
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use rppal::gpio::Level;
+    /// use std::time::Duration;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let gpio = MockGpio::new();
+    /// let arbiter = AlarmArbiter::new(22)
+    ///     .with_buzzer_active_high(true)
+    ///     .with_gpio(gpio.clone());
+    /// let handle = arbiter.start(10).await.unwrap();
+    ///
+    /// arbiter.raise(AlarmSource::Temperature);
+    /// tokio::time::sleep(Duration::from_millis(50)).await;
+    /// arbiter.stop();
+    /// handle.await.unwrap();
+    ///
+    /// // The buzzer sounded high and was left idle low
+    /// assert!(gpio.writes(22).iter().any(|&(_, level)| level == Level::High));
+    /// assert_eq!(gpio.output_level(22), Some(Level::Low));
+    /// # });
+    /// # }
+    /// ```
+    pub async fn start(&self, check_interval_ms: u64) -> Result<JoinHandle<()>, SensorError> {
+        let mut buzzer = self.gpio.output(self.buzzer_pin)?;
+        let active = self.active.clone();
+        let is_running = self.is_running.clone();
+        let idle = self.buzzer_idle_level();
+
+        is_running.store(true, Ordering::Relaxed);
+
+        Ok(tokio::spawn(async move {
+            // Initial state: turn off buzzer
+            buzzer.write(idle);
+
+            loop {
+                if !is_running.load(Ordering::Relaxed) {
+                    buzzer.write(idle); // Ensure buzzer is off
+                    break;
+                }
+
+                let current = active.lock().unwrap().iter().max().copied();
+                match current {
+                    Some(source) => {
+                        let (freq, duration_ms) = source.pattern();
+                        play_tone(&mut buzzer, freq, duration_ms, idle);
+                    }
+                    None => buzzer.write(idle),
+                }
+
+                sleep(Duration::from_millis(check_interval_ms)).await;
+            }
+        }))
+    }
+
+    /// Stop driving the buzzer
+    pub fn stop(&self) {
+        self.is_running.store(false, Ordering::Relaxed);
+    }
+}
+
+// Sound a buzzer at the given frequency for the given duration, leaving it at its idle level
+//
+// A frequency of 0 holds the buzzer idle for the duration, and frequencies
+// above MAX_TONE_FREQUENCY_HZ are clamped to it, so no tone setting can divide
+// by zero inside a monitoring loop.
+pub(crate) fn play_tone(buzzer: &mut OutputLine, freq: u32, duration_ms: u64, idle: Level) {
+    if freq == 0 {
+        buzzer.write(idle);
+        std::thread::sleep(std::time::Duration::from_millis(duration_ms));
+        return;
+    }
+
+    let half_period = 1_000_000 / freq.min(MAX_TONE_FREQUENCY_HZ) / 2;
+    let cycles = duration_ms * 1000 / (half_period as u64 * 2);
+
+    for _ in 0..cycles {
+        buzzer.write(!idle);
+        std::thread::sleep(std::time::Duration::from_micros(half_period as u64));
+        buzzer.write(idle);
+        std::thread::sleep(std::time::Duration::from_micros(half_period as u64));
+    }
+}
+
+// Level a buzzer is silent at: low for active-high buzzers, high for active-low ones
+pub(crate) fn idle_level(active_high: bool) -> Level {
+    if active_high { Level::Low } else { Level::High }
+}
+
+/// Buzzer sounding a fixed alarm tone while a hazard is detected
+///
+/// Shared by the sensors whose monitoring loop only polls a detection and
+/// sounds the buzzer on it.
+pub(crate) struct AlarmBuzzer {
+    /// Buzzer output pin
+    pin: OutputLine,
+    /// Alarm tone frequency in Hz
+    frequency_hz: u32,
+    /// Duration of each alarm tone in milliseconds
+    duration_ms: u64,
+    /// Level at which the buzzer is silent
+    idle: Level,
+}
+
+impl AlarmBuzzer {
+    pub(crate) fn new(
+        pin: OutputLine,
+        active_high: bool,
+        frequency_hz: u32,
+        duration_ms: u64,
+    ) -> Self {
+        AlarmBuzzer {
+            pin,
+            frequency_hz,
+            duration_ms,
+            idle: idle_level(active_high),
+        }
+    }
+
+    // Spawn a loop polling `detect` every `interval` and sounding the alarm
+    // tone while it reports a hazard
+    //
+    // The buzzer is held idle while nothing is detected and when the loop
+    // exits after `is_active` is cleared. `on_change` is called with the new
+    // detection state whenever it changes.
+    pub(crate) fn spawn_loop<D, C>(
+        mut self,
+        interval: Duration,
+        is_active: Arc<AtomicBool>,
+        mut detect: D,
+        mut on_change: C,
+    ) -> JoinHandle<()>
+    where
+        D: FnMut() -> bool + Send + 'static,
+        C: FnMut(bool) + Send + 'static,
+    {
+        tokio::spawn(async move {
+            self.pin.write(self.idle);
+            let mut was_detected = false;
+
+            while is_active.load(Ordering::Relaxed) {
+                let detected = detect();
+                if detected != was_detected {
+                    was_detected = detected;
+                    on_change(detected);
+                }
+
+                if detected {
+                    play_tone(
+                        &mut self.pin,
+                        self.frequency_hz,
+                        self.duration_ms,
+                        self.idle,
+                    );
+                } else {
+                    self.pin.write(self.idle);
+                }
+                sleep(interval).await;
+            }
+
+            self.pin.write(self.idle); // Ensure buzzer is off
+        })
+    }
+}
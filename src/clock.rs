@@ -0,0 +1,54 @@
+//! Timestamp unit and clock fallback shared by every sensor
+
+use crate::error::SensorError;
+
+/// Unit used for Unix epoch timestamps reported by the sensors
+///
+/// Mixing units across a deployment is the user's responsibility: consumers
+/// must know which unit each sensor was configured with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampUnit {
+    /// Seconds since the Unix epoch
+    #[default]
+    Seconds,
+    /// Milliseconds since the Unix epoch
+    Millis,
+}
+
+/// Behavior when the system clock reads earlier than the Unix epoch
+///
+/// This can happen briefly while NTP steps the clock on a Pi without an RTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClockFallback {
+    /// Report a timestamp of `0` and log a warning; the read still succeeds
+    #[default]
+    Zero,
+    /// Fail the read with `SensorError::SensorError`
+    Error,
+}
+
+impl TimestampUnit {
+    // Current time since the Unix epoch in this unit
+    pub(crate) fn now(self, fallback: ClockFallback) -> Result<u64, SensorError> {
+        let elapsed = match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            Ok(elapsed) => elapsed,
+            Err(e) => match fallback {
+                ClockFallback::Zero => {
+                    log::warn!(
+                        "System clock is before the Unix epoch, using timestamp 0: {}",
+                        e
+                    );
+                    return Ok(0);
+                }
+                ClockFallback::Error => {
+                    return Err(SensorError::SensorError(format!("Time error: {}", e)));
+                }
+            },
+        };
+
+        Ok(match self {
+            TimestampUnit::Seconds => elapsed.as_secs(),
+            TimestampUnit::Millis => elapsed.as_millis() as u64,
+        })
+    }
+}
@@ -0,0 +1,299 @@
+//! Collecting readings from several nodes over TCP (requires the `collector` feature)
+//!
+//! Each sensor node pushes its readings to one [`Collector`] with a
+//! [`CollectorClient`]. The wire format is newline-delimited JSON: a node
+//! first sends `{"node_id":"<id>"}`, then one [`NodeReading`] per line. The
+//! collector tags every reading with the ID of the connection it came in on,
+//! so a node can't report readings for another.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio::task::JoinHandle;
+
+use crate::error::SensorError;
+use crate::sensors::dht11::Dht11Data;
+use crate::sensors::fire::FireSensorData;
+
+/// Reading pushed by a node
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NodeReading {
+    /// Temperature and humidity reading
+    Temperature(Dht11Data),
+    /// Fire sensor reading
+    Fire(FireSensorData),
+}
+
+// First line a node sends, naming itself
+#[derive(Serialize, Deserialize)]
+struct Hello {
+    /// ID the node's readings are tagged with
+    node_id: String,
+}
+
+/// Latest state of a node as seen by the collector
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct NodeStatus {
+    /// Whether the node is currently connected
+    pub connected: bool,
+    /// Latest temperature reading, kept after the node disconnects
+    pub temperature: Option<Dht11Data>,
+    /// Latest fire reading, kept after the node disconnects
+    pub fire: Option<FireSensorData>,
+}
+
+// Node state and the connection currently reporting for it
+#[derive(Default)]
+struct NodeEntry {
+    /// State exposed through `Collector::nodes`
+    status: NodeStatus,
+    /// Number of the latest connection for this node
+    connection: u64,
+}
+
+// State shared by the collector and its connection tasks
+#[derive(Default)]
+struct Nodes {
+    /// Entries by node ID
+    entries: HashMap<String, NodeEntry>,
+    /// Number given to the next connection
+    next_connection: u64,
+}
+
+/// Server receiving readings from remote nodes and keeping the latest of each
+///
+/// Nodes that disconnect are marked as such and keep their last readings, and
+/// a node reconnecting under the same ID takes over its entry. Malformed lines
+/// are logged and skipped. Dropping the collector stops accepting connections.
+///
+/// # Example
+/// ```
+/// use env_monitor::collector::{Collector, CollectorClient, NodeStatus};
+/// use env_monitor::{Dht11Data, FireSensorData};
+/// use std::collections::HashMap;
+/// use std::time::Duration;
+///
+/// // Poll the collector's view until `check` holds
+/// async fn settle(collector: &Collector, check: impl Fn(&HashMap<String, NodeStatus>) -> bool) {
+///     for _ in 0..200 {
+///         if check(&collector.nodes()) {
+///             return;
+///         }
+///         tokio::time::sleep(Duration::from_millis(10)).await;
+///     }
+///     panic!("collector never settled: {:?}", collector.nodes());
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let collector = Collector::bind("127.0.0.1:0").await.unwrap();
+/// let addr = collector.local_addr();
+///
+/// let kitchen = CollectorClient::connect(addr, "kitchen").await.unwrap();
+/// let garage = CollectorClient::connect(addr, "garage").await.unwrap();
+/// let climate = Dht11Data { temperature: 21.0, humidity: 40.0, timestamp: 1_700_000_000 };
+/// let flame = FireSensorData { flame_detected: true, last_detection_timestamp: Some(1_700_000_000) };
+/// kitchen.publish_temperature(&climate).await.unwrap();
+/// garage.publish_fire(&flame).await.unwrap();
+///
+/// settle(&collector, |nodes| {
+///     nodes.get("kitchen").is_some_and(|n| n.temperature.is_some())
+///         && nodes.get("garage").is_some_and(|n| n.fire.is_some())
+/// })
+/// .await;
+/// let nodes = collector.nodes();
+/// assert_eq!(nodes["kitchen"].temperature, Some(climate));
+/// assert_eq!(nodes["kitchen"].fire, None);
+/// assert_eq!(nodes["garage"].fire, Some(flame));
+///
+/// // A disconnected node keeps its last readings
+/// drop(garage);
+/// settle(&collector, |nodes| !nodes["garage"].connected).await;
+/// assert_eq!(collector.nodes()["garage"].fire, Some(flame));
+/// assert!(collector.nodes()["kitchen"].connected);
+/// # });
+/// ```
+pub struct Collector {
+    /// Node states, updated by the connection tasks
+    nodes: Arc<Mutex<Nodes>>,
+    /// Address the collector listens on
+    local_addr: SocketAddr,
+    /// Task accepting connections
+    accept_task: JoinHandle<()>,
+}
+
+impl Collector {
+    /// Listen for nodes on the given address, e.g. `0.0.0.0:7878`
+    pub async fn bind(addr: impl ToSocketAddrs) -> Result<Self, SensorError> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .map_err(|e| SensorError::InitError(format!("Collector bind failed: {}", e)))?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|e| SensorError::InitError(format!("Collector bind failed: {}", e)))?;
+
+        let nodes = Arc::new(Mutex::new(Nodes::default()));
+        let accept_nodes = nodes.clone();
+        let accept_task = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer)) => {
+                        tokio::spawn(serve_node(stream, peer, accept_nodes.clone()));
+                    }
+                    Err(e) => log::warn!("Collector accept failed: {}", e),
+                }
+            }
+        });
+
+        Ok(Collector {
+            nodes,
+            local_addr,
+            accept_task,
+        })
+    }
+
+    /// Address the collector listens on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Latest state of every node seen so far, by node ID
+    pub fn nodes(&self) -> HashMap<String, NodeStatus> {
+        self.nodes
+            .lock()
+            .unwrap()
+            .entries
+            .iter()
+            .map(|(id, entry)| (id.clone(), entry.status))
+            .collect()
+    }
+}
+
+impl Drop for Collector {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
+
+// Receive a node's readings until it disconnects
+async fn serve_node(stream: TcpStream, peer: SocketAddr, nodes: Arc<Mutex<Nodes>>) {
+    let mut lines = BufReader::new(stream).lines();
+
+    let node_id = match lines.next_line().await {
+        Ok(Some(line)) => match serde_json::from_str::<Hello>(&line) {
+            Ok(hello) => hello.node_id,
+            Err(e) => {
+                log::warn!("Collector: {} sent an invalid hello: {}", peer, e);
+                return;
+            }
+        },
+        Ok(None) => return,
+        Err(e) => {
+            log::warn!("Collector: reading from {} failed: {}", peer, e);
+            return;
+        }
+    };
+
+    let connection = {
+        let mut nodes = nodes.lock().unwrap();
+        let connection = nodes.next_connection;
+        nodes.next_connection += 1;
+        let entry = nodes.entries.entry(node_id.clone()).or_default();
+        entry.connection = connection;
+        entry.status.connected = true;
+        connection
+    };
+    log::info!("Collector: node {} connected from {}", node_id, peer);
+
+    loop {
+        let line = match lines.next_line().await {
+            Ok(Some(line)) => line,
+            Ok(None) => break,
+            Err(e) => {
+                log::warn!("Collector: node {} connection failed: {}", node_id, e);
+                break;
+            }
+        };
+        let reading = match serde_json::from_str::<NodeReading>(&line) {
+            Ok(reading) => reading,
+            Err(e) => {
+                log::warn!(
+                    "Collector: skipping invalid reading from {}: {}",
+                    node_id,
+                    e
+                );
+                continue;
+            }
+        };
+
+        let mut nodes = nodes.lock().unwrap();
+        let status = &mut nodes.entries.entry(node_id.clone()).or_default().status;
+        match reading {
+            NodeReading::Temperature(data) => status.temperature = Some(data),
+            NodeReading::Fire(data) => status.fire = Some(data),
+        }
+    }
+
+    // A reconnection under the same ID may already have taken over the entry
+    if let Some(entry) = nodes.lock().unwrap().entries.get_mut(&node_id)
+        && entry.connection == connection
+    {
+        entry.status.connected = false;
+    }
+    log::info!("Collector: node {} disconnected", node_id);
+}
+
+/// Connection from a sensor node to a [`Collector`]
+///
+/// Readings are sent as they are published; a publish fails once the
+/// collector has gone away, after which connect again. Cloning the client is
+/// cheap and shares the connection.
+#[derive(Clone)]
+pub struct CollectorClient {
+    /// Connection to the collector, one publish at a time
+    stream: Arc<tokio::sync::Mutex<TcpStream>>,
+}
+
+impl CollectorClient {
+    /// Connect to a collector, identifying as `node_id`
+    pub async fn connect(addr: impl ToSocketAddrs, node_id: &str) -> Result<Self, SensorError> {
+        let stream = TcpStream::connect(addr)
+            .await
+            .map_err(|e| SensorError::InitError(format!("Collector connect failed: {}", e)))?;
+        let client = CollectorClient {
+            stream: Arc::new(tokio::sync::Mutex::new(stream)),
+        };
+        client
+            .send(&Hello {
+                node_id: node_id.to_string(),
+            })
+            .await?;
+        Ok(client)
+    }
+
+    /// Push a temperature reading
+    pub async fn publish_temperature(&self, data: &Dht11Data) -> Result<(), SensorError> {
+        self.send(&NodeReading::Temperature(*data)).await
+    }
+
+    /// Push a fire reading
+    pub async fn publish_fire(&self, data: &FireSensorData) -> Result<(), SensorError> {
+        self.send(&NodeReading::Fire(*data)).await
+    }
+
+    // Serialize a message to a JSON line and send it
+    async fn send<T: Serialize>(&self, message: &T) -> Result<(), SensorError> {
+        let mut line = serde_json::to_vec(message)
+            .map_err(|e| SensorError::SensorError(format!("JSON encoding failed: {}", e)))?;
+        line.push(b'\n');
+        self.stream
+            .lock()
+            .await
+            .write_all(&line)
+            .await
+            .map_err(|e| SensorError::SensorError(format!("Collector send failed: {}", e)))
+    }
+}
@@ -0,0 +1,219 @@
+//! Validation of a full sensor configuration before construction
+
+use std::collections::HashMap;
+use std::{error::Error, fmt};
+
+use crate::sensors::catalog::supported_sensors;
+
+/// Highest BCM GPIO number on the Raspberry Pi 40-pin header
+const MAX_GPIO_PIN: u8 = 27;
+
+/// Set of sensors to be wired up, typically parsed from user input
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    /// Configured sensors, in order
+    pub sensors: Vec<SensorConfig>,
+}
+
+/// One configured sensor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SensorConfig {
+    /// Sensor type name as listed by [`supported_sensors`]
+    pub sensor_type: String,
+    /// Assigned GPIO pin for each pin role
+    pub pins: Vec<(String, u8)>,
+}
+
+impl SensorConfig {
+    /// Create a sensor configuration with no pins assigned
+    pub fn new(sensor_type: impl Into<String>) -> Self {
+        SensorConfig {
+            sensor_type: sensor_type.into(),
+            pins: Vec::new(),
+        }
+    }
+
+    /// Assign a GPIO pin to a pin role
+    pub fn with_pin(mut self, role: impl Into<String>, pin: u8) -> Self {
+        self.pins.push((role.into(), pin));
+        self
+    }
+}
+
+/// Problem found in a configuration
+///
+/// Every variant carries the index of the offending sensor in
+/// [`Config::sensors`] so a setup UI can point at the right entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The sensor type is not in [`supported_sensors`]
+    UnknownSensorType {
+        /// Index of the sensor
+        sensor: usize,
+        /// Configured type name
+        sensor_type: String,
+    },
+    /// A pin role required by the sensor type has no pin assigned
+    MissingPin {
+        /// Index of the sensor
+        sensor: usize,
+        /// Required pin role
+        role: &'static str,
+    },
+    /// A pin was assigned to a role the sensor type does not have
+    UnknownPinRole {
+        /// Index of the sensor
+        sensor: usize,
+        /// Configured pin role
+        role: String,
+    },
+    /// A pin number is outside the GPIO range of the 40-pin header (0-27)
+    PinOutOfRange {
+        /// Index of the sensor
+        sensor: usize,
+        /// Pin role the number was assigned to
+        role: String,
+        /// Configured pin number
+        pin: u8,
+    },
+    /// A pin is assigned more than once
+    DuplicatePin {
+        /// Index of the sensor with the repeated assignment
+        sensor: usize,
+        /// Pin role of the repeated assignment
+        role: String,
+        /// Repeated pin number
+        pin: u8,
+        /// Index of the sensor that first used the pin
+        first_sensor: usize,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::UnknownSensorType {
+                sensor,
+                sensor_type,
+            } => write!(
+                f,
+                "Sensor {}: unknown sensor type '{}'",
+                sensor, sensor_type
+            ),
+            ConfigError::MissingPin { sensor, role } => {
+                write!(f, "Sensor {}: missing '{}' pin", sensor, role)
+            }
+            ConfigError::UnknownPinRole { sensor, role } => {
+                write!(f, "Sensor {}: unknown pin role '{}'", sensor, role)
+            }
+            ConfigError::PinOutOfRange { sensor, role, pin } => write!(
+                f,
+                "Sensor {}: '{}' pin {} is out of range 0-{}",
+                sensor, role, pin, MAX_GPIO_PIN
+            ),
+            ConfigError::DuplicatePin {
+                sensor,
+                role,
+                pin,
+                first_sensor,
+            } => write!(
+                f,
+                "Sensor {}: '{}' pin {} is already used by sensor {}",
+                sensor, role, pin, first_sensor
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+/// Check a whole configuration before constructing any sensor
+///
+/// Verifies that every sensor type is supported, that each sensor has exactly
+/// the pin roles its type requires, that pins are within the GPIO range and
+/// that no pin is used twice. All problems are reported at once, in sensor
+/// order, rather than stopping at the first.
+///
+/// # Example
+/// ```
+/// use env_monitor::config::{Config, ConfigError, SensorConfig, validate_config};
+///
+/// let config = Config {
+///     sensors: vec![
+///         SensorConfig::new("DHT11").with_pin("data", 17),
+///         SensorConfig::new("Fire").with_pin("flame", 17).with_pin("buzzer", 40),
+///         SensorConfig::new("MQ-9").with_pin("data", 5),
+///     ],
+/// };
+///
+/// let errors = validate_config(&config).unwrap_err();
+/// assert_eq!(
+///     errors,
+///     vec![
+///         ConfigError::DuplicatePin { sensor: 1, role: "flame".into(), pin: 17, first_sensor: 0 },
+///         ConfigError::PinOutOfRange { sensor: 1, role: "buzzer".into(), pin: 40 },
+///         ConfigError::UnknownSensorType { sensor: 2, sensor_type: "MQ-9".into() },
+///     ]
+/// );
+///
+/// let valid = Config { sensors: vec![SensorConfig::new("DHT22").with_pin("data", 4)] };
+/// assert!(validate_config(&valid).is_ok());
+/// ```
+pub fn validate_config(config: &Config) -> Result<(), Vec<ConfigError>> {
+    let mut errors = Vec::new();
+    let mut used_pins: HashMap<u8, usize> = HashMap::new();
+
+    for (sensor, sensor_config) in config.sensors.iter().enumerate() {
+        for (role, pin) in &sensor_config.pins {
+            if let Some(&first_sensor) = used_pins.get(pin) {
+                errors.push(ConfigError::DuplicatePin {
+                    sensor,
+                    role: role.clone(),
+                    pin: *pin,
+                    first_sensor,
+                });
+            } else {
+                used_pins.insert(*pin, sensor);
+            }
+
+            if *pin > MAX_GPIO_PIN {
+                errors.push(ConfigError::PinOutOfRange {
+                    sensor,
+                    role: role.clone(),
+                    pin: *pin,
+                });
+            }
+        }
+
+        let Some(info) = supported_sensors()
+            .iter()
+            .find(|info| info.name == sensor_config.sensor_type)
+        else {
+            errors.push(ConfigError::UnknownSensorType {
+                sensor,
+                sensor_type: sensor_config.sensor_type.clone(),
+            });
+            continue;
+        };
+
+        for role in info.pin_roles {
+            if !sensor_config.pins.iter().any(|(r, _)| r == role) {
+                errors.push(ConfigError::MissingPin { sensor, role });
+            }
+        }
+        for (role, _) in &sensor_config.pins {
+            if !info.pin_roles.contains(&role.as_str()) {
+                errors.push(ConfigError::UnknownPinRole {
+                    sensor,
+                    role: role.clone(),
+                });
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
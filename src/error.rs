@@ -1,9 +1,23 @@
 //! Custom error types for the Sensor library
 
 use rppal::gpio;
-use std::{error::Error, fmt, io};
+use std::{borrow::Cow, error::Error, fmt, io};
 
 /// Sensor library error types
+///
+/// `Timeout` and `DataValidation` are raised on the hot read path, so they hold
+/// a `Cow<'static, str>`: fixed messages are borrowed without allocating, while
+/// formatted messages can still be passed as owned strings.
+///
+/// # Example
+/// ```
+/// use env_monitor::error::SensorError;
+///
+/// let fixed = SensorError::timeout("DHT11 ready signal timed out");
+/// let dynamic = SensorError::DataValidation(format!("Humidity {}% out of range", 150).into());
+/// assert_eq!(fixed.to_string(), "Timeout error: DHT11 ready signal timed out");
+/// assert_eq!(dynamic.to_string(), "Data validation error: Humidity 150% out of range");
+/// ```
 #[derive(Debug)]
 pub enum SensorError {
     /// General IO errors
@@ -11,10 +25,18 @@ pub enum SensorError {
     /// GPIO-specific errors
     GpioError(gpio::Error),
     /// Timeout errors when communicating with sensors
-    Timeout(String),
+    #[non_exhaustive]
+    Timeout {
+        /// Description of the step that timed out
+        message: Cow<'static, str>,
+        /// Number of data bits received before the timeout (0 if the sensor
+        /// never started sending data)
+        bits_read: usize,
+    },
     /// Data validation errors (e.g. checksum failures)
-    DataValidation(String),
-    /// Initialization errors
+    DataValidation(Cow<'static, str>),
+    /// Initialization errors, including GPIO being unavailable because the
+    /// host is not a supported Raspberry Pi or lacks permissions
     InitError(String),
     /// General sensor errors
     SensorError(String),
@@ -25,7 +47,10 @@ impl fmt::Display for SensorError {
         match self {
             SensorError::IoError(err) => write!(f, "IO error: {}", err),
             SensorError::GpioError(err) => write!(f, "GPIO error: {}", err),
-            SensorError::Timeout(msg) => write!(f, "Timeout error: {}", msg),
+            SensorError::Timeout { message, bits_read } if *bits_read > 0 => {
+                write!(f, "Timeout error: {} after {} bits", message, bits_read)
+            }
+            SensorError::Timeout { message, .. } => write!(f, "Timeout error: {}", message),
             SensorError::DataValidation(msg) => write!(f, "Data validation error: {}", msg),
             SensorError::InitError(msg) => write!(f, "Initialization error: {}", msg),
             SensorError::SensorError(msg) => write!(f, "Sensor error: {}", msg),
@@ -33,6 +58,16 @@ impl fmt::Display for SensorError {
     }
 }
 
+impl SensorError {
+    /// Create a timeout error that occurred before any data bits were read
+    pub fn timeout(message: impl Into<Cow<'static, str>>) -> Self {
+        SensorError::Timeout {
+            message: message.into(),
+            bits_read: 0,
+        }
+    }
+}
+
 impl Error for SensorError {}
 
 impl From<io::Error> for SensorError {
@@ -0,0 +1,88 @@
+//! Unified sensor event bus
+
+use tokio::sync::broadcast;
+
+use crate::sensors::dht11::Dht11Data;
+use crate::sensors::fire::FireSensorData;
+
+/// Event published by a sensor
+#[derive(Debug, Clone)]
+pub enum SensorEvent {
+    /// New temperature and humidity reading
+    Temperature(Dht11Data),
+    /// Flame detected (transition from no flame to flame)
+    FlameDetected(FireSensorData),
+    /// Flame cleared (transition from flame to no flame)
+    FlameCleared(FireSensorData),
+    /// Flame detected while the alarm was suppressed by a blackout window, mute or snooze
+    AlarmSuppressed(FireSensorData),
+    /// Sensor fault with a description of the error
+    Fault(String),
+}
+
+/// Broadcast channel carrying events from every sensor to every subscriber
+///
+/// Cloning the bus is cheap and yields a handle to the same channel. Each
+/// subscriber has its own queue of `capacity` events; a subscriber that falls
+/// further behind loses the oldest events and its next `recv` returns
+/// `RecvError::Lagged` with the number skipped, after which it resumes from the
+/// oldest retained event. Publishing never blocks on slow subscribers.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<SensorEvent>,
+}
+
+impl EventBus {
+    /// Create a new event bus
+    ///
+    /// # Arguments
+    /// * `capacity` - Number of events retained per subscriber before lagging
+    ///   (at least 1; a capacity of 0 is raised to 1)
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::events::{EventBus, SensorEvent};
+    /// use env_monitor::Dht11Data;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let bus = EventBus::new(16);
+    ///     let mut display = bus.subscribe();
+    ///     let mut logger = bus.subscribe();
+    ///
+    ///     let reading = Dht11Data { temperature: 22.0, humidity: 55.0, timestamp: 0 };
+    ///     assert_eq!(bus.publish(SensorEvent::Temperature(reading)), 2);
+    ///
+    ///     for rx in [&mut display, &mut logger] {
+    ///         match rx.recv().await.unwrap() {
+    ///             SensorEvent::Temperature(data) => assert_eq!(data.temperature, 22.0),
+    ///             other => panic!("unexpected event: {:?}", other),
+    ///         }
+    ///     }
+    ///
+    ///     // A zero capacity is raised to one rather than panicking
+    ///     let bus = EventBus::new(0);
+    ///     let mut rx = bus.subscribe();
+    ///     bus.publish(SensorEvent::Temperature(reading));
+    ///     assert!(rx.recv().await.is_ok());
+    /// }
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        // broadcast::channel panics on a zero capacity
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        EventBus { sender }
+    }
+
+    /// Publish an event to all current subscribers
+    ///
+    /// # Returns
+    /// Number of subscribers the event was delivered to (0 if there are none)
+    pub fn publish(&self, event: SensorEvent) -> usize {
+        self.sender.send(event).unwrap_or(0)
+    }
+
+    /// Subscribe to events published from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<SensorEvent> {
+        self.sender.subscribe()
+    }
+}
@@ -6,6 +6,9 @@ use tokio::time::{self, Duration};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    // Library logs (e.g. flame warnings) are shown with RUST_LOG=info
+    env_logger::init();
+
     println!("环境监控系统启动");
     println!("- DHT11温湿度传感器: GPIO17");
     println!("- 火焰传感器: GPIO27, 蜂鸣器: GPIO22");
@@ -5,11 +5,46 @@
 //!
 //! ## Features
 //!
-//! - DHT11 temperature and humidity sensor interface
+//! - DHT11 and DHT22 temperature and humidity sensor interfaces
+//! - DS18B20 1-Wire temperature sensor interface
 //! - Fire detection sensor with buzzer control
+//! - Flame sensor arrays that vote before raising the alarm
+//! - MQ-2 smoke and gas sensor with buzzer control
+//! - Combined `EnvMonitor` managing a DHT11 and a fire sensor together
+//! - `SensorManager` registry reading any mix of sensors concurrently
+//! - Alarm arbitration across multiple hazard sensors
+//! - OpenMetrics text exposition of the latest readings
+//! - Bounded time-series buffer with downsampling
+//! - Min/max/average statistics accumulator for daily summaries
 //! - Async support with Tokio
+//! - Unified event bus for all sensor events
 //! - Trait-based design for extensibility
 //!
+//! ## Optional features
+//!
+//! - `serde`: derives `Serialize` and `Deserialize` for the reading types
+//!   ([`Dht11Data`], [`FireSensorData`], `GasSensorData` and `Dht11Capture`)
+//! - `mock`: scripted `MockTemperatureSensor` and `MockFireSensor` implementing
+//!   the sensor traits, and a `MockGpio` controller the hardware sensors can
+//!   be given with `with_gpio`, for testing without GPIO hardware
+//! - `testing` (implies `mock`): `FireReplay` drives the fire monitoring loop
+//!   with a script of flame pin levels and records the events it publishes
+//!   and the flame-to-alarm latency
+//! - `mqtt`: `MqttPublisher` publishing readings as JSON to an MQTT broker
+//! - `collector` (implies `serde`): a `Collector` server gathering readings
+//!   pushed as JSON over TCP by `CollectorClient`s on several nodes
+//! - `msgpack` (implies `serde`): `to_msgpack`/`from_msgpack` encode readings
+//!   as compact MessagePack for bandwidth-constrained links
+//! - `metrics`: `PrometheusMetrics` gauges and counters for Prometheus scraping
+//! - `chrono`: converts reading timestamps to `chrono::DateTime<Utc>`
+//! - `gpiod`: `GpioHandle::gpiod` takes the sensors' pins from a Linux GPIO
+//!   character device instead of rppal, where `/dev/gpiomem` is unavailable
+//! - `simulate`: `Dht11Sensor` and `FireSensor` read simulated data instead of
+//!   GPIO, with the temperature drifting on a sine wave and a flame appearing
+//!   for 10 seconds every minute; the buzzer stays silent and interrupt-driven
+//!   monitoring and the self-tests still need hardware; sensors given a
+//!   controller with `with_gpio` are not simulated
+//!
 //! ## Example
 //!
 //! ```rust,no_run
@@ -39,9 +74,28 @@
 //! ```
 
 // Re-export modules
+pub mod alarm;
+pub mod clock;
+#[cfg(feature = "collector")]
+pub mod collector;
+pub mod config;
 pub mod error;
+pub mod events;
+pub mod manager;
+pub mod metrics;
+pub mod monitor;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
 pub mod sensors;
+pub mod shutdown;
+pub mod stats;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod timeseries;
 
 // Re-export main types for convenience
+pub use clock::{ClockFallback, TimestampUnit};
 pub use sensors::dht11::Dht11Data;
 pub use sensors::fire::FireSensorData;
@@ -0,0 +1,103 @@
+//! Registry reading any number of sensors through their traits
+
+use futures::future::{join, join_all};
+
+use crate::error::SensorError;
+use crate::sensors::dht11::Dht11Data;
+use crate::sensors::fire::FireSensorData;
+use crate::sensors::traits::{FireDetector, TemperatureSensor};
+
+/// Collection of temperature sensors and fire detectors read as one
+///
+/// Sensors of any type are stored behind their trait, so a deployment can mix
+/// DHT11, DHT22 and DS18B20 sensors with flame sensors and read them all with
+/// a single call. Results are returned in the order the sensors were added.
+///
+/// # Example
+/// ```
+/// use async_trait::async_trait;
+/// use env_monitor::Dht11Data;
+/// use env_monitor::error::SensorError;
+/// use env_monitor::manager::SensorManager;
+/// use env_monitor::sensors::TemperatureSensor;
+/// use env_monitor::sensors::fire::FireSensor;
+///
+/// struct FixedSensor(f32);
+///
+/// #[async_trait]
+/// impl TemperatureSensor for FixedSensor {
+///     fn read(&self) -> Result<Dht11Data, SensorError> {
+///         Ok(Dht11Data { temperature: self.0, humidity: 50.0, timestamp: 0 })
+///     }
+///
+///     async fn read_async(&self) -> Result<Dht11Data, SensorError> {
+///         self.read()
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let manager = SensorManager::new()
+///         .add_temperature(FixedSensor(21.0))
+///         .add_temperature(FixedSensor(23.5))
+///         .add_fire(FireSensor::new(27, 22, true));
+///
+///     let (climate, fire) = manager.read_all_async().await;
+///     let temperatures: Vec<f32> = climate.iter().map(|r| r.as_ref().unwrap().temperature).collect();
+///     assert_eq!(temperatures, vec![21.0, 23.5]);
+///     assert_eq!(fire.len(), 1);
+/// }
+/// ```
+#[derive(Default)]
+pub struct SensorManager {
+    /// Registered temperature sensors, in order
+    temperature: Vec<Box<dyn TemperatureSensor>>,
+    /// Registered fire detectors, in order
+    fire: Vec<Box<dyn FireDetector>>,
+}
+
+impl SensorManager {
+    /// Create an empty sensor manager
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a temperature sensor
+    pub fn add_temperature(mut self, sensor: impl TemperatureSensor + 'static) -> Self {
+        self.temperature.push(Box::new(sensor));
+        self
+    }
+
+    /// Register a fire detector
+    pub fn add_fire(mut self, detector: impl FireDetector + 'static) -> Self {
+        self.fire.push(Box::new(detector));
+        self
+    }
+
+    /// Registered temperature sensors, in the order they were added
+    pub fn temperature_sensors(&self) -> &[Box<dyn TemperatureSensor>] {
+        &self.temperature
+    }
+
+    /// Registered fire detectors, in the order they were added
+    pub fn fire_detectors(&self) -> &[Box<dyn FireDetector>] {
+        &self.fire
+    }
+
+    /// Read every registered sensor concurrently
+    ///
+    /// Returns the temperature readings and the fire readings, each in
+    /// registration order. A failed read does not affect the others.
+    pub async fn read_all_async(
+        &self,
+    ) -> (
+        Vec<Result<Dht11Data, SensorError>>,
+        Vec<Result<FireSensorData, SensorError>>,
+    ) {
+        join(
+            join_all(self.temperature.iter().map(|sensor| sensor.read_async())),
+            join_all(self.fire.iter().map(|detector| detector.read_async())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,361 @@
+//! Metrics exposition for sensor readings
+
+use std::fmt::Write;
+#[cfg(feature = "metrics")]
+use std::sync::Arc;
+#[cfg(feature = "metrics")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "metrics")]
+use tokio::sync::broadcast::error::RecvError;
+#[cfg(feature = "metrics")]
+use tokio::task::JoinHandle;
+#[cfg(feature = "metrics")]
+use tokio::time::{Duration, interval};
+
+#[cfg(feature = "metrics")]
+use crate::error::SensorError;
+#[cfg(feature = "metrics")]
+use crate::events::{EventBus, SensorEvent};
+use crate::sensors::dht11::Dht11Data;
+use crate::sensors::fire::FireSensorData;
+#[cfg(feature = "metrics")]
+use crate::sensors::traits::{FireDetector, TemperatureSensor};
+
+/// Format the latest readings as an OpenMetrics text exposition block
+///
+/// This is pure formatting with no network access; serve the result from your
+/// own HTTP handler with content type
+/// `application/openmetrics-text; version=1.0.0; charset=utf-8`. Metrics for
+/// readings passed as `None` are omitted.
+///
+/// # Arguments
+/// * `climate` - Latest temperature and humidity reading, if any
+/// * `fire` - Latest fire sensor reading, if any
+/// * `flame_detections` - Total number of flame detections so far
+///
+/// # Example
+/// ```
+/// use env_monitor::metrics::format_openmetrics;
+/// use env_monitor::{Dht11Data, FireSensorData};
+///
+/// let climate = Dht11Data { temperature: 22.5, humidity: 60.0, timestamp: 0 };
+/// let fire = FireSensorData { flame_detected: false, last_detection_timestamp: None };
+///
+/// let text = format_openmetrics(Some(&climate), Some(&fire), 3);
+/// let expected = concat!(
+///     "# HELP env_monitor_temperature_celsius Temperature in degrees Celsius\n",
+///     "# TYPE env_monitor_temperature_celsius gauge\n",
+///     "env_monitor_temperature_celsius 22.5\n",
+///     "# HELP env_monitor_humidity_percent Relative humidity percentage\n",
+///     "# TYPE env_monitor_humidity_percent gauge\n",
+///     "env_monitor_humidity_percent 60\n",
+///     "# HELP env_monitor_flame_detected Whether a flame is currently detected\n",
+///     "# TYPE env_monitor_flame_detected gauge\n",
+///     "env_monitor_flame_detected 0\n",
+///     "# HELP env_monitor_flame_detections Number of flame detections\n",
+///     "# TYPE env_monitor_flame_detections counter\n",
+///     "env_monitor_flame_detections_total 3\n",
+///     "# EOF\n",
+/// );
+/// assert_eq!(text, expected);
+/// ```
+pub fn format_openmetrics(
+    climate: Option<&Dht11Data>,
+    fire: Option<&FireSensorData>,
+    flame_detections: u64,
+) -> String {
+    let mut out = String::new();
+
+    if let Some(data) = climate {
+        write_metric(
+            &mut out,
+            "env_monitor_temperature_celsius",
+            "gauge",
+            "Temperature in degrees Celsius",
+            data.temperature,
+        );
+        write_metric(
+            &mut out,
+            "env_monitor_humidity_percent",
+            "gauge",
+            "Relative humidity percentage",
+            data.humidity,
+        );
+    }
+
+    if let Some(data) = fire {
+        write_metric(
+            &mut out,
+            "env_monitor_flame_detected",
+            "gauge",
+            "Whether a flame is currently detected",
+            u8::from(data.flame_detected),
+        );
+    }
+
+    // OpenMetrics counters are declared without the `_total` suffix
+    let _ = writeln!(
+        out,
+        "# HELP env_monitor_flame_detections Number of flame detections"
+    );
+    let _ = writeln!(out, "# TYPE env_monitor_flame_detections counter");
+    let _ = writeln!(
+        out,
+        "env_monitor_flame_detections_total {}",
+        flame_detections
+    );
+    out.push_str("# EOF\n");
+
+    out
+}
+
+// Append the HELP, TYPE and sample lines of a single metric
+fn write_metric(
+    out: &mut String,
+    name: &str,
+    kind: &str,
+    help: &str,
+    value: impl std::fmt::Display,
+) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+    let _ = writeln!(out, "{} {}", name, value);
+}
+
+/// Prometheus collectors for sensor readings (requires the `metrics` feature)
+///
+/// Holds the gauges `env_monitor_temperature_celsius` and
+/// `env_monitor_humidity_percent` and the counter
+/// `env_monitor_flame_detections_total`, updated by the `observe_*` methods or
+/// by a background updater. The counter only increments on a new detection
+/// edge (no flame → flame), not on every read that sees a flame. Counting from
+/// reads misses flames that come and go between two reads; the event updater
+/// ([`spawn_event_updater`](PrometheusMetrics::spawn_event_updater)) counts
+/// every edge the monitoring loop sees. Clones share the same collectors.
+///
+/// # Example
+/// ```
+/// use env_monitor::metrics::PrometheusMetrics;
+/// use env_monitor::{Dht11Data, FireSensorData};
+/// use prometheus::{Encoder, Registry, TextEncoder};
+///
+/// let registry = Registry::new();
+/// let metrics = PrometheusMetrics::new().unwrap();
+/// metrics.register(&registry).unwrap();
+///
+/// metrics.observe_climate(&Dht11Data { temperature: 22.5, humidity: 60.0, timestamp: 0 });
+/// let flame = FireSensorData { flame_detected: true, last_detection_timestamp: Some(0) };
+/// metrics.observe_fire(&flame);
+/// metrics.observe_fire(&flame); // Same detection, not counted again
+///
+/// let mut text = Vec::new();
+/// TextEncoder::new().encode(&registry.gather(), &mut text).unwrap();
+/// let text = String::from_utf8(text).unwrap();
+/// assert!(text.contains("env_monitor_temperature_celsius 22.5"));
+/// assert!(text.contains("env_monitor_flame_detections_total 1"));
+/// ```
+#[cfg(feature = "metrics")]
+#[derive(Clone)]
+pub struct PrometheusMetrics {
+    /// Latest temperature in degrees Celsius
+    temperature: prometheus::Gauge,
+    /// Latest relative humidity percentage
+    humidity: prometheus::Gauge,
+    /// Number of flame detection edges
+    flame_detections: prometheus::IntCounter,
+    /// Detection state of the previous fire reading, for edge counting
+    flame_detected: Arc<AtomicBool>,
+}
+
+#[cfg(feature = "metrics")]
+impl PrometheusMetrics {
+    /// Create the collectors without registering them
+    pub fn new() -> Result<Self, SensorError> {
+        Ok(PrometheusMetrics {
+            temperature: prometheus::Gauge::new(
+                "env_monitor_temperature_celsius",
+                "Temperature in degrees Celsius",
+            )
+            .map_err(metrics_error)?,
+            humidity: prometheus::Gauge::new(
+                "env_monitor_humidity_percent",
+                "Relative humidity percentage",
+            )
+            .map_err(metrics_error)?,
+            flame_detections: prometheus::IntCounter::new(
+                "env_monitor_flame_detections_total",
+                "Number of flame detections",
+            )
+            .map_err(metrics_error)?,
+            flame_detected: Default::default(),
+        })
+    }
+
+    /// Register the collectors with a registry
+    pub fn register(&self, registry: &prometheus::Registry) -> Result<(), SensorError> {
+        registry
+            .register(Box::new(self.temperature.clone()))
+            .map_err(metrics_error)?;
+        registry
+            .register(Box::new(self.humidity.clone()))
+            .map_err(metrics_error)?;
+        registry
+            .register(Box::new(self.flame_detections.clone()))
+            .map_err(metrics_error)
+    }
+
+    /// Register the collectors with the `prometheus` crate's default registry
+    ///
+    /// Registering a second set of collectors fails, since the names clash.
+    pub fn register_default(&self) -> Result<(), SensorError> {
+        self.register(prometheus::default_registry())
+    }
+
+    /// Update the temperature and humidity gauges
+    pub fn observe_climate(&self, data: &Dht11Data) {
+        self.temperature.set(data.temperature as f64);
+        self.humidity.set(data.humidity as f64);
+    }
+
+    /// Count a new flame detection edge
+    pub fn observe_fire(&self, data: &FireSensorData) {
+        let was_detected = self
+            .flame_detected
+            .swap(data.flame_detected, Ordering::Relaxed);
+        if data.flame_detected && !was_detected {
+            self.flame_detections.inc();
+        }
+    }
+
+    /// Read a temperature sensor every `period` and update the gauges
+    ///
+    /// Failed reads are logged and leave the gauges at their last value. Abort
+    /// the returned handle to stop updating.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::metrics::PrometheusMetrics;
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let metrics = PrometheusMetrics::new()?;
+    ///     metrics.register_default()?;
+    ///     metrics.spawn_climate_updater(Dht11Sensor::new(17), Duration::from_secs(15));
+    ///     metrics.spawn_fire_updater(FireSensor::new(27, 22, true), Duration::from_millis(500));
+    ///
+    ///     // Serve prometheus::gather() from your HTTP handler...
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn spawn_climate_updater<S>(&self, sensor: S, period: Duration) -> JoinHandle<()>
+    where
+        S: TemperatureSensor + 'static,
+    {
+        let metrics = self.clone();
+        tokio::spawn(async move {
+            let mut ticks = interval(period);
+            loop {
+                ticks.tick().await;
+                match sensor.read_async().await {
+                    Ok(data) => metrics.observe_climate(&data),
+                    Err(e) => log::warn!("Sensor read failed: {}", e),
+                }
+            }
+        })
+    }
+
+    /// Read a fire detector every `period` and count new detections
+    ///
+    /// A flame shorter than `period` can start and end between two reads and
+    /// go uncounted; prefer
+    /// [`spawn_event_updater`](PrometheusMetrics::spawn_event_updater) when the
+    /// detector is monitored with an event bus. Failed reads are logged. Abort
+    /// the returned handle to stop updating.
+    pub fn spawn_fire_updater<F>(&self, detector: F, period: Duration) -> JoinHandle<()>
+    where
+        F: FireDetector + 'static,
+    {
+        let metrics = self.clone();
+        tokio::spawn(async move {
+            let mut ticks = interval(period);
+            loop {
+                ticks.tick().await;
+                match detector.read_async().await {
+                    Ok(data) => metrics.observe_fire(&data),
+                    Err(e) => log::warn!("Fire sensor read failed: {}", e),
+                }
+            }
+        })
+    }
+
+    /// Update the collectors from the events published on a bus
+    ///
+    /// Temperature events update the gauges, and every `FlameDetected` event
+    /// counts as a detection, so flames shorter than any read period are
+    /// counted as long as the monitoring loop saw them. Use it instead of the
+    /// fire updater or `observe_fire`, not alongside them, or detections are
+    /// counted twice. Events lost because the updater fell behind are logged.
+    /// The task ends when every sender of the bus is gone; abort the returned
+    /// handle to stop updating earlier.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::FireSensorData;
+    /// use env_monitor::events::{EventBus, SensorEvent};
+    /// use env_monitor::metrics::PrometheusMetrics;
+    /// use prometheus::{Encoder, Registry, TextEncoder};
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let registry = Registry::new();
+    /// let metrics = PrometheusMetrics::new().unwrap();
+    /// metrics.register(&registry).unwrap();
+    ///
+    /// let bus = EventBus::new(16);
+    /// let updater = metrics.spawn_event_updater(&bus);
+    ///
+    /// // Two brief flames, both over before any poll would have seen them
+    /// let flame = FireSensorData { flame_detected: true, last_detection_timestamp: Some(0) };
+    /// let clear = FireSensorData { flame_detected: false, last_detection_timestamp: None };
+    /// for data in [flame, clear, flame, clear] {
+    ///     let event = if data.flame_detected {
+    ///         SensorEvent::FlameDetected(data)
+    ///     } else {
+    ///         SensorEvent::FlameCleared(data)
+    ///     };
+    ///     bus.publish(event);
+    /// }
+    /// drop(bus);
+    /// updater.await.unwrap();
+    ///
+    /// let mut text = Vec::new();
+    /// TextEncoder::new().encode(&registry.gather(), &mut text).unwrap();
+    /// assert!(String::from_utf8(text).unwrap().contains("env_monitor_flame_detections_total 2"));
+    /// # });
+    /// ```
+    pub fn spawn_event_updater(&self, bus: &EventBus) -> JoinHandle<()> {
+        let metrics = self.clone();
+        let mut events = bus.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(SensorEvent::Temperature(data)) => metrics.observe_climate(&data),
+                    Ok(SensorEvent::FlameDetected(_)) => metrics.flame_detections.inc(),
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(skipped)) => {
+                        log::warn!("Metrics updater missed {} events", skipped)
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
+
+// Convert a prometheus error into a sensor error
+#[cfg(feature = "metrics")]
+fn metrics_error(e: prometheus::Error) -> SensorError {
+    SensorError::SensorError(format!("Prometheus metrics error: {}", e))
+}
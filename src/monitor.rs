@@ -0,0 +1,270 @@
+//! Combined environment monitor managing a DHT11 and a fire sensor
+
+use std::sync::Mutex;
+use tokio::task::{self, JoinHandle};
+use tokio::time::Duration;
+use tokio_stream::StreamExt;
+
+use crate::error::SensorError;
+use crate::events::{EventBus, SensorEvent};
+use crate::sensors::dht11::{Dht11Data, Dht11Sensor};
+use crate::sensors::fire::{FireSensor, FireSensorData};
+use crate::sensors::gpio::GpioHandle;
+use crate::sensors::traits::{FireDetector, TemperatureSensor};
+
+/// One-stop environment monitor: a DHT11 plus a flame sensor and buzzer
+///
+/// `start` launches fire monitoring (which sounds the buzzer on flame) and a
+/// temperature poll loop. Each temperature reading is logged and, with an
+/// event bus configured, published as a `Temperature` event alongside the
+/// fire events.
+///
+/// # Example
+/// ```no_run
+/// use env_monitor::events::{EventBus, SensorEvent};
+/// use env_monitor::monitor::EnvMonitor;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let bus = EventBus::new(16);
+///     let mut events = bus.subscribe();
+///     let monitor = EnvMonitor::new(17, 27, 22, true).with_event_bus(bus);
+///     monitor.start().await?;
+///
+///     while let Ok(event) = events.recv().await {
+///         if let SensorEvent::Temperature(data) = event {
+///             println!("Temperature: {}°C, Humidity: {}%", data.temperature, data.humidity);
+///         }
+///     }
+///
+///     monitor.stop().await;
+///     Ok(())
+/// }
+/// ```
+pub struct EnvMonitor {
+    /// Temperature and humidity sensor
+    dht11: Dht11Sensor,
+    /// Flame sensor with buzzer
+    fire: FireSensor,
+    /// Interval between temperature reads
+    temperature_interval: Duration,
+    /// Interval between fire checks in milliseconds
+    fire_check_interval_ms: u64,
+    /// Event bus that temperature readings are published to
+    event_bus: Option<EventBus>,
+    /// Fire monitoring and temperature poll tasks
+    tasks: Mutex<Tasks>,
+}
+
+// Lifecycle of the monitor's background tasks
+enum Tasks {
+    /// Not started, or stopped
+    Stopped,
+    /// Reserved by a `start` call that is still starting fire monitoring
+    Starting,
+    /// Handles of the running fire monitoring and temperature poll tasks
+    Running(JoinHandle<()>, JoinHandle<()>),
+}
+
+impl EnvMonitor {
+    /// Create a new environment monitor
+    ///
+    /// Temperature is read every 5s and the flame sensor checked every 100ms
+    /// by default.
+    ///
+    /// # Arguments
+    /// * `dht11_pin` - GPIO pin number connected to the DHT11 sensor
+    /// * `flame_pin` - GPIO pin number connected to the flame sensor
+    /// * `buzzer_pin` - GPIO pin number connected to the buzzer
+    /// * `high_active` - Flame sensor logic (true if high level indicates flame detection)
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::monitor::EnvMonitor;
+    ///
+    /// let monitor = EnvMonitor::new(17, 27, 22, true);
+    /// ```
+    pub fn new(dht11_pin: u8, flame_pin: u8, buzzer_pin: u8, high_active: bool) -> Self {
+        EnvMonitor {
+            dht11: Dht11Sensor::new(dht11_pin),
+            fire: FireSensor::new(flame_pin, buzzer_pin, high_active),
+            temperature_interval: Duration::from_secs(5),
+            fire_check_interval_ms: 100,
+            event_bus: None,
+            tasks: Mutex::new(Tasks::Stopped),
+        }
+    }
+
+    /// Set the interval between temperature reads (at least 1s for a DHT11)
+    pub fn with_temperature_interval(mut self, interval: Duration) -> Self {
+        self.temperature_interval = interval;
+        self
+    }
+
+    /// Set the interval between fire checks in milliseconds
+    pub fn with_fire_check_interval(mut self, check_interval_ms: u64) -> Self {
+        self.fire_check_interval_ms = check_interval_ms;
+        self
+    }
+
+    /// Acquire the pins of both sensors from the given GPIO controller
+    pub fn with_gpio(mut self, gpio: impl Into<GpioHandle>) -> Self {
+        let gpio = gpio.into();
+        self.dht11 = self.dht11.with_gpio(gpio.clone());
+        self.fire = self.fire.with_gpio(gpio);
+        self
+    }
+
+    /// Publish temperature readings and fire events to the given event bus
+    pub fn with_event_bus(mut self, bus: EventBus) -> Self {
+        self.fire = self.fire.with_event_bus(bus.clone());
+        self.event_bus = Some(bus);
+        self
+    }
+
+    /// Temperature and humidity sensor
+    pub fn dht11(&self) -> &Dht11Sensor {
+        &self.dht11
+    }
+
+    /// Flame sensor with buzzer
+    pub fn fire(&self) -> &FireSensor {
+        &self.fire
+    }
+
+    /// Read both sensors once
+    ///
+    /// Works while [`start`](EnvMonitor::start) is running too: the fire
+    /// reading then comes from the monitoring loop, which holds the flame pin.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::monitor::EnvMonitor;
+    ///
+    /// let monitor = EnvMonitor::new(17, 27, 22, true);
+    /// let (climate, fire) = monitor.read_all().unwrap();
+    /// println!("{}°C, flame: {}", climate.temperature, fire.flame_detected);
+    /// ```
+    pub fn read_all(&self) -> Result<(Dht11Data, FireSensorData), SensorError> {
+        Ok((self.dht11.read()?, self.fire.read()?))
+    }
+
+    /// Start fire monitoring and the temperature poll loop
+    ///
+    /// Failed temperature reads are logged and published as `Fault` events;
+    /// polling carries on. Fails if the monitor is already started or another
+    /// `start` call is still starting it; call [`stop`](EnvMonitor::stop)
+    /// first to restart it.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::monitor::EnvMonitor;
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use rppal::gpio::Level;
+    /// use std::time::Duration;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let gpio = MockGpio::new();
+    /// let monitor = EnvMonitor::new(17, 27, 22, true)
+    ///     .with_fire_check_interval(5)
+    ///     .with_gpio(gpio.clone());
+    /// // Of two concurrent starts, exactly one wins
+    /// let (first, second) = tokio::join!(monitor.start(), monitor.start());
+    /// assert!(first.is_ok() != second.is_ok());
+    /// assert!(monitor.start().await.is_err());
+    ///
+    /// // The fire reading comes from the running loop instead of the busy pin
+    /// gpio.set_level(27, Level::High);
+    /// tokio::time::sleep(Duration::from_millis(50)).await;
+    /// assert!(monitor.fire().read().unwrap().flame_detected);
+    ///
+    /// // Once stopped, reads sample the released pin again
+    /// monitor.stop().await;
+    /// assert!(!gpio.is_claimed(27));
+    /// let reads = gpio.reads(27);
+    /// assert!(monitor.fire().read().unwrap().flame_detected);
+    /// assert_eq!(gpio.reads(27), reads + 1);
+    /// # });
+    /// # }
+    /// ```
+    pub async fn start(&self) -> Result<(), SensorError> {
+        {
+            let mut tasks = self.tasks.lock().unwrap();
+            if !matches!(*tasks, Tasks::Stopped) {
+                return Err(SensorError::SensorError(
+                    "Environment monitor is already started".into(),
+                ));
+            }
+            *tasks = Tasks::Starting;
+        }
+
+        let fire_task = match self
+            .fire
+            .start_monitoring(self.fire_check_interval_ms)
+            .await
+        {
+            Ok(handle) => handle,
+            Err(e) => {
+                *self.tasks.lock().unwrap() = Tasks::Stopped;
+                return Err(e);
+            }
+        };
+
+        let event_bus = self.event_bus.clone();
+        let mut readings = self.dht11.readings(self.temperature_interval);
+
+        let temperature_task = task::spawn(async move {
+            while let Some(result) = readings.next().await {
+                let event = match result {
+                    Ok(data) => {
+                        log::info!(
+                            "Temperature: {:.1}°C, Humidity: {:.1}%",
+                            data.temperature,
+                            data.humidity
+                        );
+                        SensorEvent::Temperature(data)
+                    }
+                    Err(e) => {
+                        log::warn!("DHT11 read failed: {}", e);
+                        SensorEvent::Fault(format!("DHT11 read failed: {}", e))
+                    }
+                };
+                if let Some(bus) = &event_bus {
+                    bus.publish(event);
+                }
+            }
+        });
+
+        let mut tasks = self.tasks.lock().unwrap();
+        if !matches!(*tasks, Tasks::Starting) {
+            // `stop` ran while fire monitoring was starting
+            self.fire.stop_monitoring();
+            temperature_task.abort();
+            return Err(SensorError::SensorError(
+                "Environment monitor was stopped while starting".into(),
+            ));
+        }
+        *tasks = Tasks::Running(fire_task, temperature_task);
+        Ok(())
+    }
+
+    /// Stop both loops and wait until they have exited
+    ///
+    /// The buzzer is silenced before this returns. The temperature poll loop
+    /// is cancelled without waiting for its next tick; a blocking read already
+    /// in progress finishes in the background and its result is discarded. A
+    /// `start` call still in progress fails and stops what it started.
+    pub async fn stop(&self) {
+        let tasks = std::mem::replace(&mut *self.tasks.lock().unwrap(), Tasks::Stopped);
+        let Tasks::Running(fire_task, temperature_task) = tasks else {
+            return;
+        };
+
+        self.fire.stop_monitoring();
+        temperature_task.abort();
+        let _ = fire_task.await;
+        let _ = temperature_task.await;
+    }
+}
@@ -0,0 +1,155 @@
+//! Publishing readings to an MQTT broker (requires the `mqtt` feature)
+
+use rumqttc::{AsyncClient, MqttOptions, QoS};
+use serde::Serialize;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, MissedTickBehavior, interval};
+
+use crate::error::SensorError;
+use crate::sensors::dht11::Dht11Data;
+use crate::sensors::fire::FireSensorData;
+use crate::sensors::traits::TemperatureSensor;
+
+/// Client identifier used when the broker URL doesn't set one
+const DEFAULT_CLIENT_ID: &str = "env_monitor";
+
+/// Number of publishes queued while the connection is down
+const REQUEST_CAPACITY: usize = 16;
+
+/// Publishes readings as JSON to `<prefix>/temperature` and `<prefix>/fire`
+///
+/// Home Assistant and similar tools can subscribe to these topics directly.
+/// The connection runs in a background task that reconnects after errors;
+/// publishes made while disconnected are queued. Cloning the publisher is
+/// cheap and shares the connection.
+///
+/// # Example
+/// ```no_run
+/// use env_monitor::mqtt::MqttPublisher;
+/// use env_monitor::sensors::{FireDetector, TemperatureSensor};
+/// use env_monitor::sensors::dht11::Dht11Sensor;
+/// use env_monitor::sensors::fire::FireSensor;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let publisher = MqttPublisher::new("mqtt://192.168.1.10:1883", "home/living_room")?;
+///
+///     let climate = Dht11Sensor::new(17).read_async().await?;
+///     publisher.publish_temperature(&climate).await?;
+///
+///     let fire = FireSensor::new(27, 22, true).read_async().await?;
+///     publisher.publish_fire(&fire).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct MqttPublisher {
+    /// Handle to the broker connection
+    client: AsyncClient,
+    /// Prefix of every topic published to
+    topic_prefix: String,
+}
+
+impl MqttPublisher {
+    /// Connect to a broker, e.g. `mqtt://host:1883`
+    ///
+    /// The client ID defaults to `env_monitor` unless the URL sets one with a
+    /// `client_id` query parameter. Must be called within a Tokio runtime, as
+    /// it spawns the connection task.
+    ///
+    /// # Arguments
+    /// * `broker_url` - Broker URL with a `mqtt://` or `tcp://` scheme
+    /// * `topic_prefix` - Prefix of the topics readings are published to
+    pub fn new(broker_url: &str, topic_prefix: impl Into<String>) -> Result<Self, SensorError> {
+        let url = if broker_url.contains("client_id=") {
+            broker_url.to_string()
+        } else {
+            let separator = if broker_url.contains('?') { '&' } else { '?' };
+            format!("{}{}client_id={}", broker_url, separator, DEFAULT_CLIENT_ID)
+        };
+        let options = MqttOptions::parse_url(url)
+            .map_err(|e| SensorError::InitError(format!("Invalid MQTT broker URL: {}", e)))?;
+
+        let (client, mut event_loop) = AsyncClient::new(options, REQUEST_CAPACITY);
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = event_loop.poll().await {
+                    // Polling again reconnects; back off so a dead broker isn't hammered
+                    log::warn!("MQTT connection error: {}", e);
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+            }
+        });
+
+        Ok(MqttPublisher {
+            client,
+            topic_prefix: topic_prefix.into(),
+        })
+    }
+
+    /// Publish a temperature reading to `<prefix>/temperature`
+    pub async fn publish_temperature(&self, data: &Dht11Data) -> Result<(), SensorError> {
+        self.publish("temperature", data).await
+    }
+
+    /// Publish a fire reading to `<prefix>/fire`
+    pub async fn publish_fire(&self, data: &FireSensorData) -> Result<(), SensorError> {
+        self.publish("fire", data).await
+    }
+
+    /// Read a sensor every `period` and publish each reading
+    ///
+    /// Failed reads and publishes are logged and the loop carries on. Abort
+    /// the returned handle to stop publishing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::mqtt::MqttPublisher;
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let publisher = MqttPublisher::new("mqtt://192.168.1.10:1883", "home/living_room")?;
+    ///     let handle = publisher.spawn_publishing(Dht11Sensor::new(17), Duration::from_secs(30));
+    ///     handle.await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn spawn_publishing<S>(&self, sensor: S, period: Duration) -> JoinHandle<()>
+    where
+        S: TemperatureSensor + 'static,
+    {
+        let publisher = self.clone();
+        tokio::spawn(async move {
+            let mut ticks = interval(period);
+            ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticks.tick().await;
+                match sensor.read_async().await {
+                    Ok(data) => {
+                        if let Err(e) = publisher.publish_temperature(&data).await {
+                            log::warn!("{}", e);
+                        }
+                    }
+                    Err(e) => log::warn!("Sensor read failed: {}", e),
+                }
+            }
+        })
+    }
+
+    // Serialize a reading to JSON and publish it under the prefix
+    async fn publish<T: Serialize>(&self, topic: &str, data: &T) -> Result<(), SensorError> {
+        let payload = serde_json::to_vec(data)
+            .map_err(|e| SensorError::SensorError(format!("JSON encoding failed: {}", e)))?;
+        self.client
+            .publish(
+                format!("{}/{}", self.topic_prefix, topic),
+                QoS::AtLeastOnce,
+                false,
+                payload,
+            )
+            .await
+            .map_err(|e| SensorError::SensorError(format!("MQTT publish failed: {}", e)))
+    }
+}
@@ -0,0 +1,63 @@
+//! Static metadata about the sensor types this crate supports
+
+/// Description of a supported sensor type and the pins it needs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SensorTypeInfo {
+    /// Sensor type name
+    pub name: &'static str,
+    /// Role of each GPIO pin the sensor needs, in constructor argument order
+    pub pin_roles: &'static [&'static str],
+}
+
+impl SensorTypeInfo {
+    /// Number of GPIO pins the sensor needs
+    pub fn pin_count(&self) -> usize {
+        self.pin_roles.len()
+    }
+}
+
+const SUPPORTED_SENSORS: &[SensorTypeInfo] = &[
+    SensorTypeInfo {
+        name: "DHT11",
+        pin_roles: &["data"],
+    },
+    SensorTypeInfo {
+        name: "DHT22",
+        pin_roles: &["data"],
+    },
+    SensorTypeInfo {
+        // The data pin is chosen by the w1-gpio overlay, not the constructor
+        name: "DS18B20",
+        pin_roles: &["1-Wire data"],
+    },
+    SensorTypeInfo {
+        name: "Fire",
+        pin_roles: &["flame", "buzzer"],
+    },
+    SensorTypeInfo {
+        name: "MQ-2",
+        pin_roles: &["digital", "buzzer"],
+    },
+];
+
+/// List the sensor types this crate supports
+///
+/// Intended for driving configuration UIs without hardcoding the list. New
+/// sensor types are added here as they are implemented.
+///
+/// # Example
+/// ```
+/// use env_monitor::sensors::catalog::supported_sensors;
+///
+/// let summary: Vec<_> = supported_sensors()
+///     .iter()
+///     .map(|info| (info.name, info.pin_count()))
+///     .collect();
+/// assert_eq!(summary, [("DHT11", 1), ("DHT22", 1), ("DS18B20", 1), ("Fire", 2), ("MQ-2", 2)]);
+///
+/// let fire = supported_sensors().iter().find(|info| info.name == "Fire").unwrap();
+/// assert_eq!(fire.pin_roles, ["flame", "buzzer"]);
+/// ```
+pub fn supported_sensors() -> &'static [SensorTypeInfo] {
+    SUPPORTED_SENSORS
+}
@@ -0,0 +1,86 @@
+//! Debounced digital input shared by digital sensors
+
+use rppal::gpio::Level;
+
+/// Debounce state machine for an active-high or active-low digital input
+///
+/// Raw levels are fed in with [`update`](DebouncedInput::update); the stable
+/// state only changes after `debounce_count` consecutive samples disagree
+/// with it. A count of 0 or 1 follows the input immediately. The input starts
+/// inactive.
+///
+/// # Example
+/// ```
+/// use env_monitor::sensors::debounce::DebouncedInput;
+/// use rppal::gpio::Level;
+///
+/// let mut input = DebouncedInput::new(true, 3);
+///
+/// // A two-sample glitch is ignored
+/// assert!(!input.update(Level::High));
+/// assert!(!input.update(Level::High));
+/// assert!(!input.update(Level::Low));
+///
+/// // Three consecutive active samples switch the state
+/// assert!(!input.update(Level::High));
+/// assert!(!input.update(Level::High));
+/// assert!(input.update(Level::High));
+///
+/// // Low-active inputs interpret levels the other way round
+/// let mut input = DebouncedInput::new(false, 1);
+/// assert!(input.update(Level::Low));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DebouncedInput {
+    /// Input logic (true = high level active, false = low level active)
+    high_active: bool,
+    /// Consecutive disagreeing samples required to change state
+    debounce_count: u32,
+    /// Current stable state
+    active: bool,
+    /// Consecutive samples disagreeing with the stable state
+    pending: u32,
+}
+
+impl DebouncedInput {
+    /// Create a new debounced input
+    ///
+    /// # Arguments
+    /// * `high_active` - Input logic (true if high level means active)
+    /// * `debounce_count` - Consecutive samples required to change state
+    pub fn new(high_active: bool, debounce_count: u32) -> Self {
+        DebouncedInput {
+            high_active,
+            debounce_count,
+            active: false,
+            pending: 0,
+        }
+    }
+
+    /// Feed a raw level sample and return the debounced state
+    pub fn update(&mut self, level: Level) -> bool {
+        let raw_active = (level == Level::High) == self.high_active;
+
+        if raw_active == self.active {
+            self.pending = 0;
+        } else {
+            self.pending += 1;
+            if self.pending >= self.debounce_count {
+                self.active = raw_active;
+                self.pending = 0;
+            }
+        }
+
+        self.active
+    }
+
+    /// Change the input logic; subsequent samples are interpreted accordingly
+    pub fn set_high_active(&mut self, high_active: bool) {
+        self.high_active = high_active;
+    }
+
+    /// Current debounced state
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
@@ -0,0 +1,76 @@
+//! Automatic detection of DHT11 / DHT22 sensor modules
+
+use crate::error::SensorError;
+use crate::sensors::dht11::Dht11Sensor;
+
+/// Kind of single-wire temperature and humidity sensor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SensorKind {
+    /// DHT11: whole-number humidity and temperature bytes
+    Dht11,
+    /// DHT22 / AM2302: 16-bit humidity and signed temperature words in tenths
+    Dht22,
+}
+
+/// Read one frame from the sensor on `pin` and classify its type
+///
+/// Classification is heuristic (see [`classify_frame`]) and needs a valid
+/// frame, so a failed read should simply be retried. The DHT11 start signal is
+/// used, which is within the DHT22's accepted range.
+///
+/// # Example
+/// ```no_run
+/// use env_monitor::sensors::detect::detect_sensor_type;
+///
+/// match detect_sensor_type(17) {
+///     Ok(kind) => println!("Detected {:?}", kind),
+///     Err(e) => println!("Detection failed: {}", e),
+/// }
+/// ```
+pub fn detect_sensor_type(pin: u8) -> Result<SensorKind, SensorError> {
+    let (frame, _) = Dht11Sensor::new(pin).read_frame()?;
+
+    let sum = frame[..4].iter().fold(0u8, |acc, b| acc.wrapping_add(*b));
+    if frame[4] != sum {
+        return Err(SensorError::DataValidation("Checksum error".into()));
+    }
+
+    classify_frame(&frame).ok_or(SensorError::DataValidation(
+        "Frame matches neither DHT11 nor DHT22 layout".into(),
+    ))
+}
+
+/// Classify a raw 5-byte frame as DHT11 or DHT22
+///
+/// This is a heuristic based on value ranges. A DHT22 sends humidity as a
+/// 16-bit word in tenths of a percent (at most 1000), so its high byte is at
+/// most 3; the temperature word's magnitude (at most 800 for 80.0°C) likewise
+/// keeps its high byte at most 3 apart from the sign bit. A DHT11 sends whole
+/// percent humidity in the first byte, which for any realistic reading (4% or
+/// more) is larger than 3. A checksum is not verified here.
+///
+/// # Example
+/// ```
+/// use env_monitor::sensors::detect::{SensorKind, classify_frame};
+///
+/// // DHT11: 45% humidity, 23°C
+/// assert_eq!(classify_frame(&[45, 0, 23, 0, 68]), Some(SensorKind::Dht11));
+/// // DHT22: 65.2% humidity (0x028C), 35.1°C (0x015F)
+/// assert_eq!(classify_frame(&[0x02, 0x8C, 0x01, 0x5F, 0xEE]), Some(SensorKind::Dht22));
+/// // DHT22: -10.1°C (sign bit set)
+/// assert_eq!(classify_frame(&[0x02, 0x8C, 0x80, 0x65, 0x73]), Some(SensorKind::Dht22));
+/// // Humidity over 100% in either layout
+/// assert_eq!(classify_frame(&[200, 0, 23, 0, 223]), None);
+/// ```
+pub fn classify_frame(frame: &[u8; 5]) -> Option<SensorKind> {
+    let humidity_word = u16::from_be_bytes([frame[0], frame[1]]);
+    let temperature_word = u16::from_be_bytes([frame[2] & 0x7F, frame[3]]);
+
+    if frame[0] <= 3 && humidity_word <= 1000 && temperature_word <= 800 {
+        Some(SensorKind::Dht22)
+    } else if frame[0] <= 100 && frame[2] <= 60 {
+        Some(SensorKind::Dht11)
+    } else {
+        None
+    }
+}
@@ -1,28 +1,369 @@
 //! DHT11 temperature and humidity sensor implementation
 
 use async_trait::async_trait;
-use rppal::gpio::{Gpio, Level, Mode};
+use rppal::gpio::Level;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use tokio::task;
+use tokio::sync::{Semaphore, watch};
+use tokio::task::{self, JoinHandle};
+use tokio::time::{MissedTickBehavior, interval};
+use tokio_stream::wrappers::IntervalStream;
+use tokio_stream::{Stream, StreamExt};
 
+use crate::clock::{ClockFallback, TimestampUnit};
 use crate::error::SensorError;
+use crate::sensors::gpio::{GpioHandle, IoLine};
+use crate::sensors::monitoring::MonitorState;
+#[cfg(feature = "simulate")]
+use crate::sensors::simulate;
 use crate::sensors::traits::TemperatureSensor;
 
+/// Duration the start signal holds the line low (the DHT11 requires at least 18ms)
+const START_SIGNAL_DURATION: Duration = Duration::from_millis(20);
+
+/// Default deadline for the whole response and 40-bit transfer after the start signal
+const RESPONSE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// High-level duration separating a "0" bit (~27us) from a "1" bit (~70us)
+const BIT_THRESHOLD: Duration = Duration::from_micros(40);
+
+/// Bit durations in this range are too close to the threshold to trust
+const BORDERLINE_RANGE: std::ops::RangeInclusive<Duration> =
+    Duration::from_micros(33)..=Duration::from_micros(55);
+
+/// Borderline bits in a failed frame above which a power issue is suspected
+const BORDERLINE_LIMIT: usize = 8;
+
+/// Consecutive agreeing rejections after which the jump check re-seeds its baseline
+const JUMP_RESEED_AFTER: u32 = 3;
+
 /// DHT11 sensor data structure containing temperature and humidity readings
-#[derive(Debug, Clone, Copy)]
+///
+/// Readings compare with `==`, which is handy for tests and for detecting
+/// whether a value changed. There is no `Eq`: the fields are `f32`, and a NaN
+/// humidity (as reported by temperature-only sensors) never equals itself.
+///
+/// # Example
+/// ```
+/// use env_monitor::Dht11Data;
+///
+/// let previous = Dht11Data { temperature: 22.0, humidity: 55.0, timestamp: 0 };
+/// let current = Dht11Data { temperature: 22.0, humidity: 56.0, timestamp: 0 };
+/// assert_ne!(previous, current);
+/// assert_eq!(current, Dht11Data { temperature: 22.0, humidity: 56.0, timestamp: 0 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dht11Data {
     /// Temperature in degrees Celsius
     pub temperature: f32,
     /// Relative humidity percentage
     pub humidity: f32,
+    /// Unix timestamp in seconds when the reading was captured
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub timestamp: u64,
+}
+
+impl Dht11Data {
+    /// Capture time as a UTC date and time (requires the `chrono` feature)
+    ///
+    /// Returns `None` if the timestamp is out of range.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::Dht11Data;
+    ///
+    /// let data = Dht11Data { temperature: 25.0, humidity: 60.0, timestamp: 1_700_000_000 };
+    /// assert_eq!(data.datetime().unwrap().to_rfc3339(), "2023-11-14T22:13:20+00:00");
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(i64::try_from(self.timestamp).ok()?, 0)
+    }
+
+    /// Whole degrees of the temperature
+    ///
+    /// A genuine DHT11 only resolves 1°C, so the `f32` field implies more
+    /// precision than the sensor has. This truncates the stored value, after
+    /// any offset or rounding the sensor applied, so it isn't necessarily the
+    /// transmitted byte. Values outside `0..=255` saturate.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::Dht11Data;
+    ///
+    /// let data = Dht11Data { temperature: 23.7, humidity: 41.2, timestamp: 0 };
+    /// assert_eq!(data.temperature_whole(), 23);
+    /// assert_eq!(data.humidity_whole(), 41);
+    /// ```
+    pub fn temperature_whole(&self) -> u8 {
+        self.temperature as u8
+    }
+
+    /// Whole percent of the relative humidity
+    ///
+    /// See [`temperature_whole`](Dht11Data::temperature_whole) for the caveats.
+    pub fn humidity_whole(&self) -> u8 {
+        self.humidity as u8
+    }
+
+    /// Temperature in degrees Fahrenheit
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::Dht11Data;
+    ///
+    /// let data = Dht11Data { temperature: 25.0, humidity: 50.0, timestamp: 0 };
+    /// assert_eq!(data.temperature_fahrenheit(), 77.0);
+    /// assert_eq!(data.temperature_kelvin(), 298.15);
+    /// ```
+    pub fn temperature_fahrenheit(&self) -> f32 {
+        self.temperature * 9.0 / 5.0 + 32.0
+    }
+
+    /// Temperature in kelvin
+    pub fn temperature_kelvin(&self) -> f32 {
+        self.temperature + 273.15
+    }
+
+    /// Heat index ("feels like" temperature) in degrees Celsius
+    ///
+    /// Uses the NOAA Rothfusz regression, including its low- and high-humidity
+    /// adjustments. Below about 27°C (80°F), where the regression is not valid,
+    /// NOAA's simpler Steadman-based formula is used instead.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::Dht11Data;
+    ///
+    /// let muggy = Dht11Data { temperature: 32.0, humidity: 70.0, timestamp: 0 };
+    /// assert!((muggy.heat_index_celsius() - 40.4).abs() < 0.1);
+    ///
+    /// let mild = Dht11Data { temperature: 20.0, humidity: 50.0, timestamp: 0 };
+    /// assert!((mild.heat_index_celsius() - 19.4).abs() < 0.1);
+    /// ```
+    pub fn heat_index_celsius(&self) -> f32 {
+        let t = self.temperature_fahrenheit();
+        let rh = self.humidity;
+
+        let heat_index = if t < 80.0 {
+            0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094)
+        } else {
+            let mut hi = -42.379 + 2.049_015_2 * t + 10.143_331 * rh
+                - 0.224_755_4 * t * rh
+                - 0.006_837_83 * t * t
+                - 0.054_817_17 * rh * rh
+                + 0.001_228_74 * t * t * rh
+                + 0.000_852_82 * t * rh * rh
+                - 0.000_001_99 * t * t * rh * rh;
+
+            if rh < 13.0 && (80.0..=112.0).contains(&t) {
+                hi -= (13.0 - rh) / 4.0 * ((17.0 - (t - 95.0).abs()) / 17.0).sqrt();
+            } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+                hi += (rh - 85.0) / 10.0 * ((87.0 - t) / 5.0);
+            }
+            hi
+        };
+
+        (heat_index - 32.0) * 5.0 / 9.0
+    }
+
+    /// Dew point in degrees Celsius
+    ///
+    /// Uses the Magnus-Tetens approximation with a = 17.27 and b = 237.7°C.
+    /// Humidity is clamped to at least 0.01% so a zero reading yields a very
+    /// low but finite dew point rather than NaN or negative infinity.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::Dht11Data;
+    ///
+    /// let data = Dht11Data { temperature: 25.0, humidity: 60.0, timestamp: 0 };
+    /// assert!((data.dew_point_celsius() - 16.7).abs() < 0.1);
+    ///
+    /// // Saturated air: the dew point equals the temperature
+    /// let fog = Dht11Data { temperature: 20.0, humidity: 100.0, timestamp: 0 };
+    /// assert!((fog.dew_point_celsius() - 20.0).abs() < 0.01);
+    ///
+    /// let dry = Dht11Data { temperature: 20.0, humidity: 0.0, timestamp: 0 };
+    /// assert!(dry.dew_point_celsius().is_finite());
+    /// ```
+    pub fn dew_point_celsius(&self) -> f32 {
+        const A: f32 = 17.27;
+        const B: f32 = 237.7;
+
+        let humidity = self.humidity.max(0.01);
+        let gamma = (humidity / 100.0).ln() + A * self.temperature / (B + self.temperature);
+        B * gamma / (A - gamma)
+    }
+
+    /// Compare the temperature against a setpoint with a tolerance band
+    ///
+    /// Returns `Equal` when the temperature is within `tolerance` °C of the
+    /// setpoint (inclusive), otherwise `Less` or `Greater`. Pass a tolerance of
+    /// `0.0` for an exact comparison.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::Dht11Data;
+    /// use std::cmp::Ordering;
+    ///
+    /// let reading = |temperature| Dht11Data { temperature, humidity: 50.0, timestamp: 0 };
+    /// assert_eq!(reading(18.0).compare_temperature(21.0, 0.5), Ordering::Less);
+    /// assert_eq!(reading(21.5).compare_temperature(21.0, 0.5), Ordering::Equal);
+    /// assert_eq!(reading(23.0).compare_temperature(21.0, 0.5), Ordering::Greater);
+    /// ```
+    pub fn compare_temperature(&self, setpoint: f32, tolerance: f32) -> Ordering {
+        if (self.temperature - setpoint).abs() <= tolerance {
+            Ordering::Equal
+        } else if self.temperature < setpoint {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    /// Rate of temperature change since an earlier reading, in °C per minute
+    ///
+    /// Computed from the readings' timestamps, so both must come from the same
+    /// clock. Returns `None` unless this reading is timestamped after `previous`.
+    /// Timestamps are whole seconds, so over a span of a few seconds the
+    /// rounding alone can shift the rate by tens of percent; compare readings
+    /// further apart, or time them with a monotonic clock as monitoring does.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::Dht11Data;
+    ///
+    /// let earlier = Dht11Data { temperature: 22.0, humidity: 50.0, timestamp: 100 };
+    /// let later = Dht11Data { temperature: 25.0, humidity: 50.0, timestamp: 130 };
+    /// assert_eq!(later.temperature_rate_per_minute(&earlier), Some(6.0));
+    /// assert_eq!(earlier.temperature_rate_per_minute(&later), None);
+    /// ```
+    pub fn temperature_rate_per_minute(&self, previous: &Dht11Data) -> Option<f32> {
+        if self.timestamp <= previous.timestamp {
+            return None;
+        }
+        let minutes = (self.timestamp - previous.timestamp) as f32 / 60.0;
+        Some((self.temperature - previous.temperature) / minutes)
+    }
+
+    /// Format the reading as two lines for a 16x2 character LCD (e.g. HD44780)
+    ///
+    /// Each line is padded with spaces to exactly 16 characters, so writing it
+    /// overwrites any previous content, and truncated if a value is too wide.
+    /// A missing humidity (NaN) is shown as `--`.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::Dht11Data;
+    ///
+    /// let data = Dht11Data { temperature: 22.0, humidity: 60.0, timestamp: 0 };
+    /// assert_eq!(data.lcd_lines(), ["Temp: 22.0C     ", "Humidity: 60%   "]);
+    ///
+    /// let hot = Dht11Data { temperature: 105.5, humidity: 100.0, timestamp: 0 };
+    /// assert!(hot.lcd_lines().iter().all(|line| line.chars().count() == 16));
+    /// ```
+    pub fn lcd_lines(&self) -> [String; 2] {
+        const LCD_WIDTH: usize = 16;
+
+        let humidity = if self.humidity.is_nan() {
+            "--".to_string()
+        } else {
+            format!("{:.0}%", self.humidity)
+        };
+
+        [
+            format!("Temp: {:.1}C", self.temperature),
+            format!("Humidity: {}", humidity),
+        ]
+        .map(|line| format!("{:<width$.width$}", line, width = LCD_WIDTH))
+    }
+}
+
+/// Formats as `25.0°C, 60.0% RH`
+///
+/// Humidity is left out when it is NaN, as reported by temperature-only
+/// sensors.
+///
+/// # Example
+/// ```
+/// use env_monitor::Dht11Data;
+///
+/// let data = Dht11Data { temperature: 25.0, humidity: 60.0, timestamp: 0 };
+/// assert_eq!(data.to_string(), "25.0°C, 60.0% RH");
+///
+/// let ds18b20 = Dht11Data { temperature: 21.44, humidity: f32::NAN, timestamp: 0 };
+/// assert_eq!(ds18b20.to_string(), "21.4°C");
+/// ```
+impl fmt::Display for Dht11Data {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1}°C", self.temperature)?;
+        if !self.humidity.is_nan() {
+            write!(f, ", {:.1}% RH", self.humidity)?;
+        }
+        Ok(())
+    }
 }
 
 /// DHT11 temperature and humidity sensor implementation
+///
+/// A checksum failure where more than 8 of the 40 bits had high-level
+/// durations close to the 0/1 threshold (33–55µs) is reported as
+/// `SensorError::SensorError` hinting at a sagging supply voltage, since the
+/// sensor still responds but its timing is scrambled. Other checksum failures
+/// are reported as `SensorError::DataValidation`.
+///
+/// Clones share the monitoring state and the baseline of the jump check.
+#[derive(Clone)]
 pub struct Dht11Sensor {
     /// GPIO pin number connected to the DHT11 sensor
     gpio_pin: u8,
+    /// GPIO controller the pin is acquired from
+    gpio: GpioHandle,
+    /// Decimal places readings are rounded to (no rounding if None)
+    precision: Option<u8>,
+    /// Deadline for the response and 40-bit transfer after the start signal
+    timeout: Duration,
+    /// Whether the decimal bytes of a frame are added to the readings
+    use_decimals: bool,
+    /// Offset in °C added to every decoded temperature
+    temp_offset: f32,
+    /// Offset in percentage points added to every decoded humidity
+    humidity_offset: f32,
+    /// Maximum accepted temperature change between consecutive reads (disabled if None)
+    max_delta: Option<f32>,
+    /// Baseline of the jump check
+    jump_baseline: Arc<Mutex<JumpBaseline>>,
+    /// Limits concurrent `read_async` calls (unbounded if None)
+    read_limiter: Option<Arc<Semaphore>>,
+    /// Temperature monitoring loop state
+    monitor: MonitorState,
+    /// Whether monitoring reads immediately on start or after one interval
+    immediate_first: bool,
+    /// Humidity above which monitoring calls the alert callback (disabled if None)
+    humidity_alert: Option<(f32, HumidityCallback)>,
+    /// Temperature rise in °C/min above which monitoring calls the alert callback (disabled if None)
+    rise_alert: Option<(f32, RiseCallback)>,
+}
+
+// Readings the jump check compares against, shared by a sensor's clones
+#[derive(Default)]
+struct JumpBaseline {
+    /// Last accepted reading
+    accepted: Option<Dht11Data>,
+    /// Last rejected reading and how many agreeing rejections in a row ended with it
+    rejected: Option<(Dht11Data, u32)>,
 }
 
+/// Callback invoked with the reading when the humidity crosses above its threshold
+pub type HumidityCallback = Arc<dyn Fn(Dht11Data) + Send + Sync>;
+
+/// Callback invoked with the reading and its rise in °C/min when the temperature rises too fast
+pub type RiseCallback = Arc<dyn Fn(Dht11Data, f32) + Send + Sync>;
+
 impl Dht11Sensor {
     /// Create a new DHT11 sensor instance
     ///
@@ -36,94 +377,945 @@ impl Dht11Sensor {
     /// let sensor = Dht11Sensor::new(17);
     /// ```
     pub fn new(pin: u8) -> Self {
-        Dht11Sensor { gpio_pin: pin }
+        Dht11Sensor {
+            gpio_pin: pin,
+            gpio: GpioHandle::default(),
+            precision: None,
+            timeout: RESPONSE_TIMEOUT,
+            use_decimals: true,
+            temp_offset: 0.0,
+            humidity_offset: 0.0,
+            max_delta: None,
+            jump_baseline: Arc::default(),
+            read_limiter: None,
+            monitor: MonitorState::default(),
+            immediate_first: false,
+            humidity_alert: None,
+            rise_alert: None,
+        }
     }
 
-    // Helper function for reading sensor data
-    fn read_internal(&self) -> Result<Dht11Data, SensorError> {
-        let gpio = Gpio::new()?;
-        let mut pin = gpio.get(self.gpio_pin)?.into_io(Mode::Output);
+    /// Acquire the pin from the given GPIO controller
+    ///
+    /// Accepts an opened `rppal::gpio::Gpio` (or `Arc<Gpio>`) to share one
+    /// controller between sensors, or a `MockGpio` with the `mock` feature. By
+    /// default the sensor opens the controller itself on first use.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use rppal::gpio::Gpio;
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_gpio(Gpio::new().unwrap());
+    /// ```
+    pub fn with_gpio(mut self, gpio: impl Into<GpioHandle>) -> Self {
+        self.gpio = gpio.into();
+        self
+    }
+
+    /// Limit how many `read_async` calls may be in flight at once
+    ///
+    /// Each async read occupies a thread of Tokio's blocking pool; under heavy
+    /// load further reads queue behind it with unbounded latency. With a limit
+    /// set, a read beyond `max_pending` fails immediately with
+    /// `SensorError::SensorError("read queue full")` instead of queueing. The
+    /// trade-off is that callers must handle (and possibly retry) rejected
+    /// reads. Unlimited by default, and a limit of 0 also means unlimited.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_max_pending_reads(2);
+    /// ```
+    pub fn with_max_pending_reads(mut self, max_pending: usize) -> Self {
+        self.read_limiter = (max_pending > 0).then(|| Arc::new(Semaphore::new(max_pending)));
+        self
+    }
 
-        // Send start signal
-        pin.write(Level::Low);
-        std::thread::sleep(Duration::from_millis(20)); // At least 18ms low level
-        pin.write(Level::High);
+    /// Round returned temperature and humidity to the given number of decimal places
+    ///
+    /// No rounding is applied by default. Although this mainly keeps logged values
+    /// tidy, it changes the returned value itself, not just its display.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_precision(1);
+    /// ```
+    pub fn with_precision(mut self, decimals: u8) -> Self {
+        self.precision = Some(decimals);
+        self
+    }
 
-        // Switch to input mode to receive data
-        pin.set_mode(Mode::Input);
+    /// Correct a sensor that reads consistently off from a reference
+    ///
+    /// The offsets are added to every reading after the frame is decoded and
+    /// range-checked, so they don't affect checksum or range validation; the
+    /// calibrated humidity is clamped to 0-100%. No offset by default.
+    ///
+    /// # Arguments
+    /// * `temp_offset` - Added to the temperature in °C (e.g. `-2.0` for a sensor reading 2°C high)
+    /// * `humidity_offset` - Added to the humidity in percentage points
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_calibration(-2.0, 0.0);
+    /// ```
+    pub fn with_calibration(mut self, temp_offset: f32, humidity_offset: f32) -> Self {
+        self.temp_offset = temp_offset;
+        self.humidity_offset = humidity_offset;
+        self
+    }
 
-        // Wait for DHT11 response
-        let timeout = Instant::now() + Duration::from_millis(100);
-        while pin.read() == Level::High {
-            if Instant::now() > timeout {
-                return Err(SensorError::Timeout(
-                    "Waiting for DHT11 response timed out".into(),
-                ));
-            }
+    /// Reject readings whose temperature jumps implausibly from the previous one
+    ///
+    /// Disabled by default. When enabled, the first reading is accepted
+    /// unchecked and becomes the baseline; any later reading differing from the
+    /// last accepted one by more than `max_delta` °C is rejected with
+    /// `SensorError::DataValidation` and does not move the baseline. Choose a
+    /// delta larger than any genuine change expected between two reads.
+    ///
+    /// A corrupt first reading, or a genuine step such as the sensor being
+    /// moved, would otherwise lock the sensor out for good. So once three
+    /// readings in a row are rejected, each within `max_delta` of the one
+    /// before it, the third is accepted and becomes the new baseline. A lone
+    /// spike never agrees with the readings around it and stays rejected.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_max_delta(10.0);
+    /// ```
+    ///
+    /// Against a `MockGpio` (with the `mock` feature):
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::error::SensorError;
+    /// use env_monitor::sensors::TemperatureSensor;
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use env_monitor::sensors::mock::MockGpio;
+    ///
+    /// const AT_22: [u8; 5] = [45, 0, 22, 0, 67];
+    /// const AT_45: [u8; 5] = [45, 0, 45, 0, 90];
+    ///
+    /// let gpio = MockGpio::new();
+    /// let sensor = Dht11Sensor::new(17).with_max_delta(10.0).with_gpio(gpio.clone());
+    ///
+    /// // Read a frame, retrying the checksum errors a preempted mock read can cause
+    /// let read = |frame| loop {
+    ///     gpio.script_response(17, MockGpio::dht_waveform(frame));
+    ///     match sensor.read() {
+    ///         Err(SensorError::DataValidation(msg)) if msg.contains("Checksum") => continue,
+    ///         result => break result,
+    ///     }
+    /// };
+    ///
+    /// // The first reading seeds the baseline and a lone spike is rejected
+    /// assert_eq!(read(AT_22).unwrap().temperature, 22.0);
+    /// assert!(read(AT_45).is_err());
+    /// assert_eq!(read(AT_22).unwrap().temperature, 22.0);
+    ///
+    /// // A lasting step is accepted on the third agreeing reading
+    /// assert!(read(AT_45).is_err());
+    /// assert!(read(AT_45).is_err());
+    /// assert_eq!(read(AT_45).unwrap().temperature, 45.0);
+    /// assert_eq!(read(AT_45).unwrap().temperature, 45.0);
+    /// # }
+    /// ```
+    pub fn with_max_delta(mut self, max_delta: f32) -> Self {
+        self.max_delta = Some(max_delta);
+        self
+    }
+
+    /// Set the deadline for the sensor's response and 40-bit transfer
+    ///
+    /// The deadline starts when the start signal ends and applies to every
+    /// wait of the read, so it bounds the whole handshake and transfer. The
+    /// default of 100ms is ample for a healthy sensor; a longer timeout can
+    /// avoid spurious `SensorError::Timeout` errors on a heavily loaded Pi or
+    /// with long wires, at the cost of slower failure detection.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use std::time::Duration;
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_timeout(Duration::from_millis(250));
+    /// assert_eq!(sensor.worst_case_read_duration(), Duration::from_millis(270));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Choose whether the transmitted decimal bytes are included in readings
+    ///
+    /// Bytes 1 and 3 of a frame carry the humidity and temperature decimals in
+    /// tenths. A genuine DHT11 sends zero there, but some clones populate them.
+    /// They are included by default; disable this for clones that send garbage
+    /// decimals, so readings are whole numbers.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_decimals(false);
+    /// ```
+    pub fn with_decimals(mut self, use_decimals: bool) -> Self {
+        self.use_decimals = use_decimals;
+        self
+    }
+
+    // Apply configured rounding and the jump check to a decoded reading
+    fn postprocess(&self, mut data: Dht11Data) -> Result<Dht11Data, SensorError> {
+        if let Some(decimals) = self.precision {
+            let factor = 10f32.powi(decimals as i32);
+            data.temperature = (data.temperature * factor).round() / factor;
+            data.humidity = (data.humidity * factor).round() / factor;
         }
 
-        while pin.read() == Level::Low {
-            if Instant::now() > timeout {
-                return Err(SensorError::Timeout(
-                    "DHT11 response signal timed out".into(),
-                ));
+        self.check_plausible(data)
+    }
+
+    // Apply the jump check to a decoded reading and update the baseline
+    fn check_plausible(&self, data: Dht11Data) -> Result<Dht11Data, SensorError> {
+        let Some(max_delta) = self.max_delta else {
+            return Ok(data);
+        };
+
+        let mut baseline = self.jump_baseline.lock().unwrap();
+        if let Some(previous) = baseline.accepted {
+            let delta = (data.temperature - previous.temperature).abs();
+            if delta > max_delta {
+                // Rejections that agree with each other point at a genuine change
+                let streak = match baseline.rejected {
+                    Some((rejected, streak))
+                        if (data.temperature - rejected.temperature).abs() <= max_delta =>
+                    {
+                        streak + 1
+                    }
+                    _ => 1,
+                };
+                if streak < JUMP_RESEED_AFTER {
+                    baseline.rejected = Some((data, streak));
+                    return Err(SensorError::DataValidation(
+                        format!(
+                            "Implausible temperature jump from {}°C to {}°C",
+                            previous.temperature, data.temperature
+                        )
+                        .into(),
+                    ));
+                }
+                log::warn!(
+                    "Jump check re-seeded at {}°C after {} consistent readings",
+                    data.temperature,
+                    streak
+                );
             }
         }
 
-        while pin.read() == Level::High {
-            if Instant::now() > timeout {
-                return Err(SensorError::Timeout("DHT11 ready signal timed out".into()));
+        baseline.accepted = Some(data);
+        baseline.rejected = None;
+        Ok(data)
+    }
+
+    /// Estimate the worst-case duration of a single read
+    ///
+    /// The estimate is the start signal low time plus the configured
+    /// [timeout](Dht11Sensor::with_timeout), which bounds the sensor's
+    /// acknowledgement and the full 40-bit transfer. It does not include time
+    /// spent waiting for a blocking thread in `read_async`.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use std::time::Duration;
+    ///
+    /// let sensor = Dht11Sensor::new(17);
+    /// assert_eq!(sensor.worst_case_read_duration(), Duration::from_millis(120));
+    /// ```
+    pub fn worst_case_read_duration(&self) -> Duration {
+        START_SIGNAL_DURATION + self.timeout
+    }
+
+    /// Check whether the DHT11 answers the start signal without reading any data
+    ///
+    /// Only the sensor's initial response pulse is awaited; the 40 data bits are
+    /// not decoded, so this returns no temperature or humidity. Useful as a quick
+    /// "is it connected?" check.
+    ///
+    /// # Returns
+    /// `true` if the sensor acknowledged, `false` if no response arrived in time
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17);
+    /// if !sensor.ping().unwrap_or(false) {
+    ///     println!("DHT11 not responding, check wiring");
+    /// }
+    /// ```
+    ///
+    /// Against a `MockGpio` (with the `mock` feature):
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use rppal::gpio::Level;
+    /// use std::time::Duration;
+    ///
+    /// let gpio = MockGpio::new();
+    /// let sensor = Dht11Sensor::new(17).with_gpio(gpio.clone());
+    ///
+    /// // Nothing connected: the pull-up holds the line high
+    /// gpio.set_level(17, Level::High);
+    /// assert!(!sensor.ping().unwrap());
+    ///
+    /// // The sensor acknowledges with an 80us low, 80us high pulse
+    /// gpio.script_response(17, [
+    ///     (Level::Low, Duration::from_micros(80)),
+    ///     (Level::High, Duration::from_micros(80)),
+    /// ]);
+    /// assert!(sensor.ping().unwrap());
+    /// # }
+    /// ```
+    pub fn ping(&self) -> Result<bool, SensorError> {
+        if self.gpio.is_simulated() {
+            return Ok(true);
+        }
+
+        let mut pin = self.gpio.io(self.gpio_pin)?;
+
+        send_start_signal(&mut pin, START_SIGNAL_DURATION);
+
+        // The sensor acknowledges by pulling the line low, then high
+        let timeout = Instant::now() + self.timeout;
+        for level in [Level::High, Level::Low] {
+            while pin.read() == level {
+                if Instant::now() > timeout {
+                    return Ok(false);
+                }
             }
         }
 
-        // Read 40 bits of data (8bit humidity integer + 8bit humidity decimal + 8bit temperature integer + 8bit temperature decimal + 8bit checksum)
-        let mut data = [0u8; 5];
+        Ok(true)
+    }
 
-        for byte in data.iter_mut() {
-            for j in 0..8 {
-                // Wait for 50us low level to pass
-                while pin.read() == Level::Low {
-                    if Instant::now() > timeout {
-                        return Err(SensorError::Timeout(
-                            "Timed out while reading data bit".into(),
-                        ));
-                    }
+    /// Verify the sensor is wired and responding before trusting its readings
+    ///
+    /// Performs one full read and checks that it completes without a timeout,
+    /// checksum failure or out-of-range value (0-50°C, 0-100% humidity). Any
+    /// failure is reported as `SensorError::InitError` so deployments can fail
+    /// fast on startup.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17);
+    /// if let Err(e) = sensor.self_test() {
+    ///     eprintln!("{}", e);
+    ///     std::process::exit(1);
+    /// }
+    /// ```
+    pub fn self_test(&self) -> Result<(), SensorError> {
+        self.read()
+            .map(|_| ())
+            .map_err(|e| SensorError::InitError(format!("DHT11 self-test failed: {}", e)))
+    }
+
+    /// Capture the raw bit timings of one read for offline decoding
+    ///
+    /// Acquisition is separated from decoding: the 40 measured high-level
+    /// durations are returned undecoded, so a capture taken on a field unit can
+    /// be sent elsewhere and decoded with [`decode_capture`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::dht11::{Dht11Sensor, decode_capture};
+    ///
+    /// let sensor = Dht11Sensor::new(17);
+    /// let capture = sensor.capture().unwrap();
+    /// println!("High durations (us): {:?}", capture.high_durations_us);
+    /// println!("Decoded: {:?}", decode_capture(&capture));
+    /// ```
+    pub fn capture(&self) -> Result<Dht11Capture, SensorError> {
+        let durations = self.read_bit_durations()?;
+
+        Ok(Dht11Capture {
+            high_durations_us: durations.iter().map(|d| d.as_micros() as u32).collect(),
+        })
+    }
+
+    // Perform the start handshake and read the raw 5-byte frame without validating it,
+    // also returning how many bits had borderline high-level durations
+    pub(crate) fn read_frame(&self) -> Result<([u8; 5], usize), SensorError> {
+        Ok(frame_from_durations(&self.read_bit_durations()?))
+    }
+
+    // Perform the start handshake and measure the high-level duration of all 40 bits
+    fn read_bit_durations(&self) -> Result<[Duration; 40], SensorError> {
+        #[cfg(feature = "simulate")]
+        if self.gpio.is_simulated() {
+            return Ok(simulate::dht11_bit_durations());
+        }
+
+        read_bit_durations(
+            &self.gpio,
+            self.gpio_pin,
+            START_SIGNAL_DURATION,
+            self.timeout,
+        )
+    }
+
+    /// Read the sensor and compare the temperature against a setpoint
+    ///
+    /// Collapses the read-then-compare pattern of thermostat-style logic; see
+    /// [`Dht11Data::compare_temperature`] for the tolerance semantics.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use std::cmp::Ordering;
+    ///
+    /// let sensor = Dht11Sensor::new(17);
+    /// match sensor.read_relative_to(21.0, 0.5) {
+    ///     Ok((_, Ordering::Less)) => println!("Heating on"),
+    ///     Ok((_, _)) => println!("Heating off"),
+    ///     Err(e) => println!("Read failed: {}", e),
+    /// }
+    /// ```
+    pub fn read_relative_to(
+        &self,
+        setpoint: f32,
+        tolerance: f32,
+    ) -> Result<(Dht11Data, Ordering), SensorError> {
+        let data = self.postprocess(self.read_internal()?)?;
+        Ok((data, data.compare_temperature(setpoint, tolerance)))
+    }
+
+    /// Choose whether monitoring reads immediately on start or after one interval
+    ///
+    /// Defaults to `false` (delayed): the DHT11 needs about a second after
+    /// power-up before its readings are reliable, so reading the instant
+    /// monitoring starts tends to yield a timeout or a stale value. Enable this
+    /// when the sensor has long been powered.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_immediate_first(true);
+    /// ```
+    pub fn with_immediate_first(mut self, immediate_first: bool) -> Self {
+        self.immediate_first = immediate_first;
+        self
+    }
+
+    /// Alert from monitoring when the humidity crosses above a threshold
+    ///
+    /// Sustained high humidity is what leads to mold and condensation. While
+    /// [`start_monitoring`](TemperatureSensor::start_monitoring) runs, `on_alert` is
+    /// called with the reading once per crossing above `high_threshold` (in
+    /// percent RH), independently of the temperature alert; it is re-armed once
+    /// a reading is at or below the threshold again. Disabled by default.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_humidity_high_threshold(70.0, |data| {
+    ///     println!("Humidity too high! ({:.0}% RH)", data.humidity);
+    /// });
+    /// ```
+    pub fn with_humidity_high_threshold<F>(mut self, high_threshold: f32, on_alert: F) -> Self
+    where
+        F: Fn(Dht11Data) + Send + Sync + 'static,
+    {
+        self.humidity_alert = Some((high_threshold, Arc::new(on_alert)));
+        self
+    }
+
+    /// Alert from monitoring when the temperature rises faster than a threshold
+    ///
+    /// A fast-rising temperature is an early fire indicator, often before a
+    /// flame sensor trips. While
+    /// [`start_monitoring`](TemperatureSensor::start_monitoring) runs, each reading is
+    /// compared with the previous successful one, timed with a monotonic clock
+    /// rather than the whole-second timestamps, and `on_alert` is called with
+    /// the reading and the rate once the rise exceeds `celsius_per_minute`. Like
+    /// the other alerts it fires once per crossing and is re-armed when the rate
+    /// drops back to or below the threshold. The DHT11's 1°C resolution makes
+    /// the rate coarse over short intervals, so set the threshold well above
+    /// the step a single degree produces at the monitoring interval. Disabled
+    /// by default.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_rise_rate_threshold(5.0, |data, rate| {
+    ///     println!("Temperature rising fast: {:.1}°C/min (now {:.1}°C)", rate, data.temperature);
+    /// });
+    /// ```
+    pub fn with_rise_rate_threshold<F>(mut self, celsius_per_minute: f32, on_alert: F) -> Self
+    where
+        F: Fn(Dht11Data, f32) + Send + Sync + 'static,
+    {
+        self.rise_alert = Some((celsius_per_minute, Arc::new(on_alert)));
+        self
+    }
+
+    /// Stream a reading every `period`
+    ///
+    /// The first reading is taken immediately; if a read takes longer than the
+    /// period, the next one is delayed rather than bunched up. Failed reads are
+    /// yielded as `Err` items and the stream carries on, so combinators such as
+    /// `take`, `filter` or `throttle` from `tokio_stream::StreamExt` apply
+    /// directly. The stream never ends by itself. The DHT11 needs at least one
+    /// second between reads.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use std::time::Duration;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let sensor = Dht11Sensor::new(17);
+    ///     let mut readings = sensor.readings(Duration::from_secs(2)).filter_map(Result::ok).take(10);
+    ///
+    ///     while let Some(data) = readings.next().await {
+    ///         println!("Temperature: {}°C, Humidity: {}%", data.temperature, data.humidity);
+    ///     }
+    /// }
+    /// ```
+    pub fn readings(
+        &self,
+        period: Duration,
+    ) -> impl Stream<Item = Result<Dht11Data, SensorError>> + Send + Unpin + use<> {
+        let sensor = self.clone();
+        let mut ticks = interval(period);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        IntervalStream::new(ticks).then(move |_| {
+            let sensor = sensor.clone();
+            Box::pin(async move { sensor.read_async().await })
+        })
+    }
+
+    /// Read in the background and publish the latest reading on a watch channel
+    ///
+    /// Reads every `period` (at least one second for a DHT11) and stores each
+    /// successful reading in the channel, so any number of consumers can clone
+    /// the receiver and look at the latest value without touching the sensor.
+    /// The value is `None` until the first successful read; failed reads are
+    /// logged and leave the previous value in place. The background task stops
+    /// once every receiver has been dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let sensor = Dht11Sensor::new(17);
+    ///     let mut latest = sensor.spawn_watch(Duration::from_secs(2));
+    ///
+    ///     let display = latest.clone();
+    ///     tokio::spawn(async move {
+    ///         if let Some(data) = *display.borrow() {
+    ///             println!("Temperature: {}°C", data.temperature);
+    ///         }
+    ///     });
+    ///
+    ///     while latest.changed().await.is_ok() {
+    ///         println!("New reading: {:?}", *latest.borrow());
+    ///     }
+    /// }
+    /// ```
+    pub fn spawn_watch(&self, period: Duration) -> watch::Receiver<Option<Dht11Data>> {
+        let (tx, rx) = watch::channel(None);
+        let sensor = self.clone();
+        let mut ticks = interval(period);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = tx.closed() => break,
+                    _ = ticks.tick() => {}
                 }
 
-                // Measure high level duration to determine data bit (0 or 1)
-                let start = Instant::now();
-                while pin.read() == Level::High {
-                    if Instant::now() > timeout {
-                        return Err(SensorError::Timeout(
-                            "Timed out during high level data bit reading".into(),
-                        ));
+                match sensor.read_async().await {
+                    Ok(data) => {
+                        if tx.send(Some(data)).is_err() {
+                            break;
+                        }
                     }
+                    Err(e) => log::warn!("DHT11 watch read failed: {}", e),
                 }
-                let duration = start.elapsed();
+            }
+        });
 
-                // If high level lasts about 70 microseconds, it's a data bit "1"
-                if duration > Duration::from_micros(40) {
-                    *byte |= 1 << (7 - j);
+        rx
+    }
+
+    /// Read, retrying transient failures
+    ///
+    /// Timeouts and data validation errors (checksum, range, jump check) are
+    /// part of normal DHT11 operation and are retried up to `attempts` reads
+    /// in total, waiting `backoff` (at least 1ms) between tries. GPIO and other
+    /// errors won't fix themselves and are returned immediately. If every
+    /// attempt fails, the last error is returned. The datasheet asks for 1s
+    /// between reads, so a backoff well below that may see repeated failures.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use std::time::Duration;
+    ///
+    /// let sensor = Dht11Sensor::new(17);
+    /// let data = sensor.read_with_retries(3, Duration::from_secs(1)).unwrap();
+    /// println!("Temperature: {}°C, Humidity: {}%", data.temperature, data.humidity);
+    /// ```
+    pub fn read_with_retries(
+        &self,
+        attempts: usize,
+        backoff: Duration,
+    ) -> Result<Dht11Data, SensorError> {
+        const MIN_BACKOFF: Duration = Duration::from_millis(1);
+
+        let mut attempt = 1;
+        loop {
+            match self.read() {
+                Err(SensorError::Timeout { .. } | SensorError::DataValidation(_))
+                    if attempt < attempts =>
+                {
+                    attempt += 1;
+                    std::thread::sleep(backoff.max(MIN_BACKOFF));
                 }
+                result => return result,
+            }
+        }
+    }
+
+    /// Read several frames and decode their bitwise majority
+    ///
+    /// For very noisy environments: `frames` frames are read and each of the 40
+    /// bits is decided by majority vote across the frames that were received,
+    /// so corruption confined to a minority of frames is outvoted before the
+    /// checksum is verified. Frames that fail to arrive are skipped; if none
+    /// arrive, the last read error is returned. Use an odd number of frames.
+    ///
+    /// The DHT11 needs at least one second between reads, which is respected
+    /// between frames, so this takes roughly `frames - 1` seconds plus one
+    /// [`worst_case_read_duration`](Dht11Sensor::worst_case_read_duration) per frame.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// let sensor = Dht11Sensor::new(17);
+    /// match sensor.read_voted(3) {
+    ///     Ok(data) => println!("Temperature: {}°C, Humidity: {}%", data.temperature, data.humidity),
+    ///     Err(e) => println!("Read failed: {}", e),
+    /// }
+    /// ```
+    pub fn read_voted(&self, frames: usize) -> Result<Dht11Data, SensorError> {
+        const MIN_READ_INTERVAL: Duration = Duration::from_secs(1);
+
+        let mut received = Vec::with_capacity(frames);
+        let mut last_error = SensorError::SensorError("No frames requested".into());
+
+        for i in 0..frames {
+            if i > 0 {
+                std::thread::sleep(MIN_READ_INTERVAL);
+            }
+            match self.read_frame() {
+                Ok((data, _)) => received.push(data),
+                Err(e) => last_error = e,
             }
         }
 
+        if received.is_empty() {
+            return Err(last_error);
+        }
+
+        let data = Self::decode_frame(&majority_vote(&received), 0, self.use_decimals)?;
+        self.postprocess(self.calibrate(check_range(data)?))
+    }
+
+    // Helper function for reading sensor data
+    fn read_internal(&self) -> Result<Dht11Data, SensorError> {
+        let (data, borderline_bits) = self.read_frame()?;
+        let data = Self::decode_frame(&data, borderline_bits, self.use_decimals)?;
+        Ok(self.calibrate(check_range(data)?))
+    }
+
+    // Apply the calibration offsets to a decoded reading
+    fn calibrate(&self, mut data: Dht11Data) -> Dht11Data {
+        data.temperature += self.temp_offset;
+        data.humidity = (data.humidity + self.humidity_offset).clamp(0.0, 100.0);
+        data
+    }
+
+    // Verify a raw frame's checksum and decode it into a reading
+    fn decode_frame(
+        data: &[u8; 5],
+        borderline_bits: usize,
+        use_decimals: bool,
+    ) -> Result<Dht11Data, SensorError> {
         // Verify checksum
-        if data[4] != (data[0] + data[1] + data[2] + data[3]) {
+        let sum = data[0]
+            .wrapping_add(data[1])
+            .wrapping_add(data[2])
+            .wrapping_add(data[3]);
+        if data[4] != sum {
+            // A sagging 3.3V rail still lets the sensor respond but smears bit
+            // timings towards the threshold, so many borderline bits in a failed
+            // frame point at the power supply rather than random noise
+            if borderline_bits > BORDERLINE_LIMIT {
+                return Err(SensorError::SensorError(format!(
+                    "Checksum error with {} of 40 bits near the timing threshold; \
+                     possible supply voltage issue, check the 3.3V rail and wiring",
+                    borderline_bits
+                )));
+            }
             return Err(SensorError::DataValidation("Checksum error".into()));
         }
 
-        // Process DHT11 temperature and humidity data (not using decimal parts due to low precision of DHT11)
-        let humidity = data[0] as f32;
-        let temperature = data[2] as f32;
+        // Process DHT11 temperature and humidity data, with the decimal parts in tenths
+        let (humidity, temperature) = if use_decimals {
+            (
+                data[0] as f32 + data[1] as f32 / 10.0,
+                data[2] as f32 + data[3] as f32 / 10.0,
+            )
+        } else {
+            (data[0] as f32, data[2] as f32)
+        };
 
         Ok(Dht11Data {
             temperature,
             humidity,
+            timestamp: TimestampUnit::Seconds.now(ClockFallback::Zero)?,
         })
     }
 }
 
+// Reject readings outside the DHT11's rated range, which only a glitched frame
+// with a coincidentally valid checksum can produce
+fn check_range(data: Dht11Data) -> Result<Dht11Data, SensorError> {
+    if !(0.0..=100.0).contains(&data.humidity) {
+        return Err(SensorError::DataValidation(
+            format!("Humidity {}% out of range 0-100", data.humidity).into(),
+        ));
+    }
+    if !(0.0..=50.0).contains(&data.temperature) {
+        return Err(SensorError::DataValidation(
+            format!("Temperature {}°C out of range 0-50", data.temperature).into(),
+        ));
+    }
+    Ok(data)
+}
+
+/// Raw bit timings captured from one DHT11 read
+///
+/// Holds the high-level duration of each of the 40 data bits in microseconds,
+/// in transmission order. A "0" bit lasts about 27us and a "1" bit about 70us.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dht11Capture {
+    /// High-level duration of each data bit in microseconds
+    pub high_durations_us: Vec<u32>,
+}
+
+/// Decode a captured read's bits and checksum into a raw reading
+///
+/// Only the frame decoding of a live read is repeated: the bit timings are
+/// thresholded and the checksum verified, with decimal bytes included as in a
+/// sensor's default configuration. The rated range check, calibration offsets,
+/// rounding and jump check a sensor applies after decoding are all skipped,
+/// so the result is what the sensor sent rather than what `read` would return.
+/// The reading is timestamped when it is decoded, not when it was captured.
+///
+/// # Example
+/// ```
+/// use env_monitor::sensors::dht11::{Dht11Capture, decode_capture};
+///
+/// let capture = |frame: [u8; 5]| Dht11Capture {
+///     high_durations_us: frame
+///         .iter()
+///         .flat_map(|byte| (0..8).rev().map(move |bit| if byte >> bit & 1 == 1 { 70 } else { 27 }))
+///         .collect(),
+/// };
+///
+/// // Frame 45% / 23°C: [45, 0, 23, 0, 68]
+/// let data = decode_capture(&capture([45, 0, 23, 0, 68])).unwrap();
+/// assert_eq!((data.temperature, data.humidity), (23.0, 45.0));
+///
+/// // The checksum is the low 8 bits of the sum: 90 + 90 + 50 + 40 = 270 -> 14.
+/// // 54°C is outside the DHT11's rated range, which is not checked here
+/// let data = decode_capture(&capture([90, 90, 50, 40, 14])).unwrap();
+/// assert_eq!((data.temperature, data.humidity), (54.0, 99.0));
+///
+/// // Clones that populate the decimal bytes: 45.5% / 23.7°C
+/// let data = decode_capture(&capture([45, 5, 23, 7, 80])).unwrap();
+/// assert_eq!((data.temperature, data.humidity), (23.7, 45.5));
+/// ```
+pub fn decode_capture(capture: &Dht11Capture) -> Result<Dht11Data, SensorError> {
+    if capture.high_durations_us.len() != 40 {
+        return Err(SensorError::DataValidation(
+            format!(
+                "Capture has {} bit timings, expected 40",
+                capture.high_durations_us.len()
+            )
+            .into(),
+        ));
+    }
+
+    let durations: Vec<Duration> = capture
+        .high_durations_us
+        .iter()
+        .map(|us| Duration::from_micros(*us as u64))
+        .collect();
+    let (data, borderline_bits) = frame_from_durations(&durations);
+
+    Dht11Sensor::decode_frame(&data, borderline_bits, true)
+}
+
+// Perform the single-wire start handshake shared by the DHT11 and DHT22 and
+// measure the high-level duration of all 40 data bits
+pub(crate) fn read_bit_durations(
+    gpio: &GpioHandle,
+    gpio_pin: u8,
+    start_signal: Duration,
+    response_timeout: Duration,
+) -> Result<[Duration; 40], SensorError> {
+    let mut pin = gpio.io(gpio_pin)?;
+
+    // Send start signal
+    send_start_signal(&mut pin, start_signal);
+
+    // Wait for sensor response
+    let timeout = Instant::now() + response_timeout;
+    while pin.read() == Level::High {
+        if Instant::now() > timeout {
+            return Err(SensorError::timeout(
+                "Waiting for sensor response timed out",
+            ));
+        }
+    }
+
+    while pin.read() == Level::Low {
+        if Instant::now() > timeout {
+            return Err(SensorError::timeout("Sensor response signal timed out"));
+        }
+    }
+
+    while pin.read() == Level::High {
+        if Instant::now() > timeout {
+            return Err(SensorError::timeout("Sensor ready signal timed out"));
+        }
+    }
+
+    // Read 40 bits of data (8bit humidity integer + 8bit humidity decimal + 8bit temperature integer + 8bit temperature decimal + 8bit checksum)
+    let mut durations = [Duration::ZERO; 40];
+
+    for (bits_read, duration) in durations.iter_mut().enumerate() {
+        // Wait for 50us low level to pass
+        while pin.read() == Level::Low {
+            if Instant::now() > timeout {
+                return Err(SensorError::Timeout {
+                    message: "Timed out while reading data bit".into(),
+                    bits_read,
+                });
+            }
+        }
+
+        // Measure high level duration to determine data bit (0 or 1)
+        let start = Instant::now();
+        while pin.read() == Level::High {
+            if Instant::now() > timeout {
+                return Err(SensorError::Timeout {
+                    message: "Timed out during high level data bit reading".into(),
+                    bits_read,
+                });
+            }
+        }
+        *duration = start.elapsed();
+    }
+
+    Ok(durations)
+}
+
+// Send the start signal and switch the pin to input mode
+fn send_start_signal(pin: &mut IoLine, low_time: Duration) {
+    pin.write(Level::Low);
+    std::thread::sleep(low_time);
+    pin.write(Level::High);
+
+    // Switch to input mode to receive data
+    pin.set_input();
+}
+
+// Turn 40 high-level durations into a frame, counting borderline bits
+pub(crate) fn frame_from_durations(durations: &[Duration]) -> ([u8; 5], usize) {
+    let mut data = [0u8; 5];
+    let mut borderline_bits = 0;
+
+    for (i, duration) in durations.iter().enumerate() {
+        // If high level lasts about 70 microseconds, it's a data bit "1"
+        if *duration > BIT_THRESHOLD {
+            data[i / 8] |= 1 << (7 - i % 8);
+        }
+
+        if BORDERLINE_RANGE.contains(duration) {
+            borderline_bits += 1;
+        }
+    }
+
+    (data, borderline_bits)
+}
+
+/// Combine several raw frames by bitwise majority vote
+///
+/// Each bit of the result is set if it is set in more than half of the frames.
+///
+/// # Example
+/// ```
+/// use env_monitor::sensors::dht11::majority_vote;
+///
+/// let frame = [45, 0, 23, 0, 68];
+/// let mut corrupted = frame;
+/// corrupted[2] ^= 0b0000_0100;
+///
+/// assert_eq!(majority_vote(&[frame, corrupted, frame]), frame);
+/// ```
+pub fn majority_vote(frames: &[[u8; 5]]) -> [u8; 5] {
+    let mut result = [0u8; 5];
+
+    for (i, byte) in result.iter_mut().enumerate() {
+        for bit in 0..8 {
+            let mask = 1 << bit;
+            let ones = frames.iter().filter(|f| f[i] & mask != 0).count();
+            if ones * 2 > frames.len() {
+                *byte |= mask;
+            }
+        }
+    }
+
+    result
+}
+
 #[async_trait]
 impl TemperatureSensor for Dht11Sensor {
     /// Synchronously read temperature and humidity data
@@ -143,7 +1335,7 @@ impl TemperatureSensor for Dht11Sensor {
     /// }
     /// ```
     fn read(&self) -> Result<Dht11Data, SensorError> {
-        self.read_internal()
+        self.postprocess(self.read_internal()?)
     }
 
     /// Asynchronously read temperature and humidity data
@@ -167,14 +1359,117 @@ impl TemperatureSensor for Dht11Sensor {
     /// }
     /// ```
     async fn read_async(&self) -> Result<Dht11Data, SensorError> {
-        let pin = self.gpio_pin;
+        let sensor = self.clone();
+        let permit = match &self.read_limiter {
+            Some(limiter) => Some(
+                limiter
+                    .clone()
+                    .try_acquire_owned()
+                    .map_err(|_| SensorError::SensorError("read queue full".into()))?,
+            ),
+            None => None,
+        };
 
         // Execute the read operation in a blocking task
-        task::spawn_blocking(move || {
-            let sensor = Dht11Sensor::new(pin);
-            sensor.read()
+        let data = task::spawn_blocking(move || {
+            let _permit = permit;
+            sensor.read_internal()
         })
         .await
-        .map_err(|e| SensorError::SensorError(format!("Task join error: {}", e)))?
+        .map_err(|e| SensorError::SensorError(format!("Task join error: {}", e)))??;
+
+        self.postprocess(data)
+    }
+
+    /// Start monitoring the temperature against a high threshold
+    ///
+    /// The DHT11 needs at least 1000ms between reads, so keep `interval_ms` at
+    /// or above that; the first read waits one interval unless
+    /// [`with_immediate_first`](Dht11Sensor::with_immediate_first) is set. A
+    /// humidity alert set with
+    /// [`with_humidity_high_threshold`](Dht11Sensor::with_humidity_high_threshold)
+    /// is checked the same way on every reading, as is a rise rate alert set
+    /// with [`with_rise_rate_threshold`](Dht11Sensor::with_rise_rate_threshold).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::TemperatureSensor;
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let sensor = Dht11Sensor::new(17);
+    ///     let handle = sensor.start_monitoring(2000, 40.0, |data| {
+    ///         println!("Temperature too high! ({:.1}°C)", data.temperature);
+    ///     })?;
+    ///
+    ///     // Do other things...
+    ///
+    ///     sensor.stop_monitoring();
+    ///     handle.await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    fn start_monitoring<F>(
+        &self,
+        interval_ms: u64,
+        high_threshold: f32,
+        on_alert: F,
+    ) -> Result<JoinHandle<()>, SensorError>
+    where
+        F: Fn(Dht11Data) + Send + 'static,
+    {
+        let humidity_alert = self.humidity_alert.clone();
+        let rise_alert = self.rise_alert.clone();
+
+        // Whether the previous reading was above each threshold
+        let mut humidity_above = false;
+        let mut rising_fast = false;
+        // Previous successful reading and when it arrived, the baseline of the rise rate
+        let mut previous: Option<(Dht11Data, Instant)> = None;
+
+        self.monitor.spawn(
+            self.clone(),
+            interval_ms,
+            self.immediate_first,
+            high_threshold,
+            on_alert,
+            move |data| {
+                let now = Instant::now();
+
+                if let Some((threshold, on_humidity_alert)) = &humidity_alert {
+                    if data.humidity > *threshold {
+                        if !humidity_above {
+                            on_humidity_alert(data);
+                        }
+                        humidity_above = true;
+                    } else {
+                        humidity_above = false;
+                    }
+                }
+
+                if let Some((threshold, on_rise_alert)) = &rise_alert
+                    && let Some((prev, prev_at)) = previous
+                    && now > prev_at
+                {
+                    let minutes = now.duration_since(prev_at).as_secs_f32() / 60.0;
+                    let rate = (data.temperature - prev.temperature) / minutes;
+                    if rate > *threshold {
+                        if !rising_fast {
+                            on_rise_alert(data, rate);
+                        }
+                        rising_fast = true;
+                    } else {
+                        rising_fast = false;
+                    }
+                }
+                previous = Some((data, now));
+            },
+        )
+    }
+
+    /// Stop temperature monitoring
+    fn stop_monitoring(&self) {
+        self.monitor.stop();
     }
 }
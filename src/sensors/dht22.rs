@@ -0,0 +1,221 @@
+//! DHT22 / AM2302 temperature and humidity sensor implementation
+
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::task::{self, JoinHandle};
+
+use crate::clock::{ClockFallback, TimestampUnit};
+use crate::error::SensorError;
+use crate::sensors::dht11::{Dht11Data, frame_from_durations, read_bit_durations};
+use crate::sensors::gpio::GpioHandle;
+use crate::sensors::monitoring::MonitorState;
+use crate::sensors::traits::TemperatureSensor;
+
+/// Duration the start signal holds the line low (the DHT22 expects about 1ms)
+const START_SIGNAL_DURATION: Duration = Duration::from_micros(1100);
+
+/// Deadline for the whole response and 40-bit transfer after the start signal
+const RESPONSE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// DHT22 / AM2302 temperature and humidity sensor implementation
+///
+/// The DHT22 uses the same single-wire 40-bit protocol as the DHT11 with a
+/// shorter start signal, but sends humidity and temperature as 16-bit words
+/// in tenths, giving 0.1 resolution and temperatures down to -40°C.
+///
+/// Clones share the monitoring state.
+#[derive(Clone)]
+pub struct Dht22Sensor {
+    /// GPIO pin number connected to the DHT22 sensor
+    gpio_pin: u8,
+    /// GPIO controller the pin is acquired from
+    gpio: GpioHandle,
+    /// Temperature monitoring loop state
+    monitor: MonitorState,
+}
+
+impl Dht22Sensor {
+    /// Create a new DHT22 sensor instance
+    ///
+    /// # Arguments
+    /// * `pin` - GPIO pin number connected to the DHT22 sensor
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht22::Dht22Sensor;
+    ///
+    /// let sensor = Dht22Sensor::new(17);
+    /// ```
+    pub fn new(pin: u8) -> Self {
+        Dht22Sensor {
+            gpio_pin: pin,
+            gpio: GpioHandle::default(),
+            monitor: MonitorState::default(),
+        }
+    }
+
+    /// Acquire the pin from the given GPIO controller
+    ///
+    /// Accepts an opened `rppal::gpio::Gpio` (or `Arc<Gpio>`) to share one
+    /// controller between sensors, or a `MockGpio` with the `mock` feature. By
+    /// default the sensor opens the controller itself on first use.
+    pub fn with_gpio(mut self, gpio: impl Into<GpioHandle>) -> Self {
+        self.gpio = gpio.into();
+        self
+    }
+
+    // Helper function for reading sensor data
+    fn read_internal(&self) -> Result<Dht11Data, SensorError> {
+        let durations = read_bit_durations(
+            &self.gpio,
+            self.gpio_pin,
+            START_SIGNAL_DURATION,
+            RESPONSE_TIMEOUT,
+        )?;
+        let (data, _) = frame_from_durations(&durations);
+        decode_dht22_frame(&data)
+    }
+}
+
+/// Decode a raw 5-byte DHT22 frame into a reading
+///
+/// Bytes 0-1 hold humidity and bytes 2-3 temperature, both in tenths; the
+/// high bit of byte 2 is the temperature sign. Byte 4 must equal the 8-bit
+/// sum of the first four bytes.
+///
+/// # Example
+/// ```
+/// use env_monitor::sensors::dht22::decode_dht22_frame;
+///
+/// // 65.2% humidity, 35.1°C
+/// let data = decode_dht22_frame(&[0x02, 0x8C, 0x01, 0x5F, 0xEE]).unwrap();
+/// assert_eq!((data.humidity, data.temperature), (65.2, 35.1));
+///
+/// // 65.2% humidity, -10.1°C
+/// let data = decode_dht22_frame(&[0x02, 0x8C, 0x80, 0x65, 0x73]).unwrap();
+/// assert_eq!(data.temperature, -10.1);
+///
+/// assert!(decode_dht22_frame(&[0x02, 0x8C, 0x01, 0x5F, 0x00]).is_err());
+/// ```
+pub fn decode_dht22_frame(data: &[u8; 5]) -> Result<Dht11Data, SensorError> {
+    // Verify checksum
+    let sum = data[..4].iter().fold(0u8, |acc, b| acc.wrapping_add(*b));
+    if data[4] != sum {
+        return Err(SensorError::DataValidation("Checksum error".into()));
+    }
+
+    let humidity = u16::from_be_bytes([data[0], data[1]]) as f32 / 10.0;
+    let magnitude = u16::from_be_bytes([data[2] & 0x7F, data[3]]) as f32 / 10.0;
+    let temperature = if data[2] & 0x80 != 0 {
+        -magnitude
+    } else {
+        magnitude
+    };
+
+    Ok(Dht11Data {
+        temperature,
+        humidity,
+        timestamp: TimestampUnit::Seconds.now(ClockFallback::Zero)?,
+    })
+}
+
+#[async_trait]
+impl TemperatureSensor for Dht22Sensor {
+    /// Synchronously read temperature and humidity data
+    ///
+    /// # Returns
+    /// Temperature and humidity data or error
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::TemperatureSensor;
+    /// use env_monitor::sensors::dht22::Dht22Sensor;
+    ///
+    /// let sensor = Dht22Sensor::new(17);
+    /// match sensor.read() {
+    ///     Ok(data) => println!("Temperature: {}°C, Humidity: {}%", data.temperature, data.humidity),
+    ///     Err(e) => println!("Read failed: {}", e),
+    /// }
+    /// ```
+    fn read(&self) -> Result<Dht11Data, SensorError> {
+        self.read_internal()
+    }
+
+    /// Asynchronously read temperature and humidity data
+    ///
+    /// # Returns
+    /// Temperature and humidity data or error
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::TemperatureSensor;
+    /// use env_monitor::sensors::dht22::Dht22Sensor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let sensor = Dht22Sensor::new(17);
+    ///     match sensor.read_async().await {
+    ///         Ok(data) => println!("Temperature: {}°C, Humidity: {}%", data.temperature, data.humidity),
+    ///         Err(e) => println!("Read failed: {}", e),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn read_async(&self) -> Result<Dht11Data, SensorError> {
+        let sensor = self.clone();
+
+        // Execute the read operation in a blocking task
+        task::spawn_blocking(move || sensor.read_internal())
+            .await
+            .map_err(|e| SensorError::SensorError(format!("Task join error: {}", e)))?
+    }
+
+    /// Start monitoring the temperature against a high threshold
+    ///
+    /// The first read happens after one interval, since the DHT22 needs about
+    /// a second after power-up; keep `interval_ms` at 2000 or more, the
+    /// DHT22's minimum sampling period.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::TemperatureSensor;
+    /// use env_monitor::sensors::dht22::Dht22Sensor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let sensor = Dht22Sensor::new(17);
+    ///     let handle = sensor.start_monitoring(2000, 40.0, |data| {
+    ///         println!("Temperature too high! ({:.1}°C)", data.temperature);
+    ///     })?;
+    ///
+    ///     // Do other things...
+    ///
+    ///     sensor.stop_monitoring();
+    ///     handle.await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    fn start_monitoring<F>(
+        &self,
+        interval_ms: u64,
+        high_threshold: f32,
+        on_alert: F,
+    ) -> Result<JoinHandle<()>, SensorError>
+    where
+        F: Fn(Dht11Data) + Send + 'static,
+    {
+        self.monitor.spawn(
+            self.clone(),
+            interval_ms,
+            false,
+            high_threshold,
+            on_alert,
+            |_| {},
+        )
+    }
+
+    /// Stop temperature monitoring
+    fn stop_monitoring(&self) {
+        self.monitor.stop();
+    }
+}
@@ -0,0 +1,246 @@
+//! DS18B20 1-Wire temperature sensor implementation
+//!
+//! The sensor is read through the Linux `w1-gpio` driver. On a Raspberry Pi,
+//! enable it by adding `dtoverlay=w1-gpio` to `/boot/config.txt` (data line on
+//! GPIO 4 by default, with a 4.7kΩ pull-up to 3.3V) and rebooting. Each probe
+//! then appears as `/sys/bus/w1/devices/28-xxxxxxxxxxxx/w1_slave`.
+
+use async_trait::async_trait;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tokio::task::{self, JoinHandle};
+
+use crate::clock::{ClockFallback, TimestampUnit};
+use crate::error::SensorError;
+use crate::sensors::dht11::Dht11Data;
+use crate::sensors::monitoring::MonitorState;
+use crate::sensors::traits::TemperatureSensor;
+
+/// Directory where the w1 driver exposes 1-Wire devices
+const W1_DEVICES_DIR: &str = "/sys/bus/w1/devices";
+
+/// Device ID prefix of the DS18B20 1-Wire family code
+const DS18B20_FAMILY_PREFIX: &str = "28-";
+
+/// DS18B20 1-Wire temperature sensor implementation
+///
+/// Clones share the monitoring state.
+#[derive(Clone)]
+pub struct Ds18b20Sensor {
+    /// Path to the device's `w1_slave` file
+    path: PathBuf,
+    /// Temperature monitoring loop state
+    monitor: MonitorState,
+}
+
+impl Ds18b20Sensor {
+    /// Create a new DS18B20 sensor instance
+    ///
+    /// # Arguments
+    /// * `device_id` - 1-Wire device ID, e.g. `28-0316a2797aff`
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::ds18b20::Ds18b20Sensor;
+    ///
+    /// let sensor = Ds18b20Sensor::new("28-0316a2797aff");
+    /// ```
+    pub fn new(device_id: &str) -> Self {
+        Ds18b20Sensor {
+            path: PathBuf::from(W1_DEVICES_DIR)
+                .join(device_id)
+                .join("w1_slave"),
+            monitor: MonitorState::default(),
+        }
+    }
+
+    /// Find every DS18B20 probe on the 1-Wire bus
+    ///
+    /// Lists `/sys/bus/w1/devices` and returns a sensor for each device with
+    /// the DS18B20 family code (`28-`), sorted by device ID. Other 1-Wire
+    /// devices and the bus master entries are skipped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::TemperatureSensor;
+    /// use env_monitor::sensors::ds18b20::Ds18b20Sensor;
+    ///
+    /// for sensor in Ds18b20Sensor::discover().unwrap() {
+    ///     println!("{}: {:?}", sensor.device_id(), sensor.read());
+    /// }
+    /// ```
+    pub fn discover() -> Result<Vec<Ds18b20Sensor>, SensorError> {
+        Self::discover_in(Path::new(W1_DEVICES_DIR))
+    }
+
+    /// Find every DS18B20 probe in the given w1 devices directory
+    ///
+    /// Like [`discover`](Ds18b20Sensor::discover), for a sysfs tree mounted
+    /// somewhere other than `/sys/bus/w1/devices`.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::ds18b20::Ds18b20Sensor;
+    /// use std::fs;
+    ///
+    /// let dir = std::env::temp_dir().join("env_monitor_w1_discover");
+    /// for device in ["28-0316a2797aff", "28-0000075a5c1b", "w1_bus_master1", "10-000802b4c4d2"] {
+    ///     fs::create_dir_all(dir.join(device)).unwrap();
+    /// }
+    ///
+    /// let ids: Vec<String> = Ds18b20Sensor::discover_in(&dir)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|sensor| sensor.device_id().to_string())
+    ///     .collect();
+    /// assert_eq!(ids, ["28-0000075a5c1b", "28-0316a2797aff"]);
+    /// # fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn discover_in(dir: &Path) -> Result<Vec<Ds18b20Sensor>, SensorError> {
+        let mut device_ids = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let name = entry?.file_name();
+            if let Some(name) = name.to_str()
+                && name.starts_with(DS18B20_FAMILY_PREFIX)
+            {
+                device_ids.push(name.to_string());
+            }
+        }
+        device_ids.sort();
+
+        Ok(device_ids
+            .into_iter()
+            .map(|id| Ds18b20Sensor {
+                path: dir.join(id).join("w1_slave"),
+                monitor: MonitorState::default(),
+            })
+            .collect())
+    }
+
+    /// 1-Wire device ID of the probe, e.g. `28-0316a2797aff`
+    pub fn device_id(&self) -> &str {
+        self.path
+            .parent()
+            .and_then(|device_dir| device_dir.file_name())
+            .and_then(|id| id.to_str())
+            .unwrap_or_default()
+    }
+
+    // Helper function for reading sensor data
+    fn read_internal(&self) -> Result<Dht11Data, SensorError> {
+        let contents = fs::read_to_string(&self.path)?;
+        let temperature = parse_w1_slave(&contents)?;
+
+        // The DS18B20 has no humidity sensor
+        Ok(Dht11Data {
+            temperature,
+            humidity: f32::NAN,
+            timestamp: TimestampUnit::Seconds.now(ClockFallback::Zero)?,
+        })
+    }
+}
+
+/// Parse the contents of a DS18B20 `w1_slave` file into degrees Celsius
+///
+/// The first line must end in `YES` (CRC check passed) and the second line
+/// must carry the temperature as `t=<millidegrees>`.
+///
+/// # Example
+/// ```
+/// use env_monitor::sensors::ds18b20::parse_w1_slave;
+///
+/// let contents = "72 01 4b 46 7f ff 0e 10 57 : crc=57 YES\n\
+///                 72 01 4b 46 7f ff 0e 10 57 t=23125\n";
+/// assert_eq!(parse_w1_slave(contents).unwrap(), 23.125);
+///
+/// let bad_crc = "72 01 4b 46 7f ff 0e 10 57 : crc=00 NO\n\
+///                72 01 4b 46 7f ff 0e 10 57 t=23125\n";
+/// assert!(parse_w1_slave(bad_crc).is_err());
+/// ```
+pub fn parse_w1_slave(contents: &str) -> Result<f32, SensorError> {
+    let mut lines = contents.lines();
+
+    let crc_line = lines
+        .next()
+        .ok_or(SensorError::DataValidation("Empty w1_slave file".into()))?;
+    if !crc_line.trim_end().ends_with("YES") {
+        return Err(SensorError::DataValidation("DS18B20 CRC error".into()));
+    }
+
+    let millidegrees = lines
+        .next()
+        .and_then(|line| line.rsplit_once("t="))
+        .ok_or(SensorError::DataValidation(
+            "Missing temperature in w1_slave file".into(),
+        ))?
+        .1
+        .trim()
+        .parse::<i32>()
+        .map_err(|e| SensorError::DataValidation(format!("Invalid temperature: {}", e).into()))?;
+
+    Ok(millidegrees as f32 / 1000.0)
+}
+
+#[async_trait]
+impl TemperatureSensor for Ds18b20Sensor {
+    /// Synchronously read temperature data
+    ///
+    /// # Returns
+    /// Temperature data (humidity is always NaN) or error
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::TemperatureSensor;
+    /// use env_monitor::sensors::ds18b20::Ds18b20Sensor;
+    ///
+    /// let sensor = Ds18b20Sensor::new("28-0316a2797aff");
+    /// match sensor.read() {
+    ///     Ok(data) => println!("Temperature: {}°C", data.temperature),
+    ///     Err(e) => println!("Read failed: {}", e),
+    /// }
+    /// ```
+    fn read(&self) -> Result<Dht11Data, SensorError> {
+        self.read_internal()
+    }
+
+    /// Asynchronously read temperature data
+    ///
+    /// # Returns
+    /// Temperature data (humidity is always NaN) or error
+    async fn read_async(&self) -> Result<Dht11Data, SensorError> {
+        let sensor = self.clone();
+
+        // Execute the read operation in a blocking task
+        task::spawn_blocking(move || sensor.read_internal())
+            .await
+            .map_err(|e| SensorError::SensorError(format!("Task join error: {}", e)))?
+    }
+
+    /// Start monitoring the temperature against a high threshold
+    ///
+    /// The first read happens immediately; a conversion takes up to 750ms, so
+    /// intervals below a second mostly wait on the probe.
+    fn start_monitoring<F>(
+        &self,
+        interval_ms: u64,
+        high_threshold: f32,
+        on_alert: F,
+    ) -> Result<JoinHandle<()>, SensorError>
+    where
+        F: Fn(Dht11Data) + Send + 'static,
+    {
+        self.monitor.spawn(
+            self.clone(),
+            interval_ms,
+            true,
+            high_threshold,
+            on_alert,
+            |_| {},
+        )
+    }
+
+    /// Stop temperature monitoring
+    fn stop_monitoring(&self) {
+        self.monitor.stop();
+    }
+}
@@ -0,0 +1,193 @@
+//! Moving-average smoothing for temperature and humidity readings
+
+use async_trait::async_trait;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+use crate::error::SensorError;
+use crate::sensors::dht11::{Dht11Data, Dht11Sensor};
+use crate::sensors::monitoring::MonitorState;
+use crate::sensors::traits::TemperatureSensor;
+
+/// Sensor wrapper returning the moving average of the last N readings
+///
+/// Each successful read of the wrapped sensor is added to a ring buffer of
+/// `window` samples and the mean of the buffered samples is returned, which
+/// evens out the degree or two a DHT11 jumps between reads. Until the buffer
+/// fills, the average covers the readings received so far. Failed reads are
+/// returned as errors and leave the buffer untouched.
+///
+/// Works with any [`TemperatureSensor`], and implements the trait itself so it
+/// drops in wherever the trait is used. Clones share the wrapped sensor, the
+/// sample window and the monitoring state.
+///
+/// # Example
+/// ```
+/// use async_trait::async_trait;
+/// use env_monitor::Dht11Data;
+/// use env_monitor::error::SensorError;
+/// use env_monitor::sensors::TemperatureSensor;
+/// use env_monitor::sensors::filtered::Dht11Filtered;
+/// use std::sync::Mutex;
+///
+/// struct Noisy(Mutex<Vec<f32>>);
+///
+/// #[async_trait]
+/// impl TemperatureSensor for Noisy {
+///     fn read(&self) -> Result<Dht11Data, SensorError> {
+///         let temperature = self.0.lock().unwrap().remove(0);
+///         Ok(Dht11Data { temperature, humidity: 50.0, timestamp: 0 })
+///     }
+///
+///     async fn read_async(&self) -> Result<Dht11Data, SensorError> {
+///         self.read()
+///     }
+/// }
+///
+/// let sensor = Dht11Filtered::new(Noisy(Mutex::new(vec![20.0, 22.0, 21.0, 25.0])), 3);
+/// assert_eq!(sensor.read().unwrap().temperature, 20.0);
+/// assert_eq!(sensor.read().unwrap().temperature, 21.0);
+/// assert_eq!(sensor.read().unwrap().temperature, 21.0);
+/// // The oldest sample (20.0) drops out of the window
+/// assert_eq!(sensor.read().unwrap().temperature, 68.0 / 3.0);
+/// ```
+pub struct Dht11Filtered<S = Dht11Sensor> {
+    /// Wrapped sensor
+    sensor: Arc<S>,
+    /// Number of samples averaged
+    window: usize,
+    /// Most recent samples, oldest first
+    samples: Arc<Mutex<VecDeque<Dht11Data>>>,
+    /// Monitoring run state, stopped by `stop_monitoring`
+    monitor: MonitorState,
+}
+
+impl<S> Clone for Dht11Filtered<S> {
+    fn clone(&self) -> Self {
+        Dht11Filtered {
+            sensor: Arc::clone(&self.sensor),
+            window: self.window,
+            samples: Arc::clone(&self.samples),
+            monitor: self.monitor.clone(),
+        }
+    }
+}
+
+impl<S: TemperatureSensor> Dht11Filtered<S> {
+    /// Wrap a sensor, averaging over the last `window` readings (minimum 1)
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use env_monitor::sensors::filtered::Dht11Filtered;
+    ///
+    /// let sensor = Dht11Filtered::new(Dht11Sensor::new(17), 5);
+    /// ```
+    pub fn new(sensor: S, window: usize) -> Self {
+        let window = window.max(1);
+        Dht11Filtered {
+            sensor: Arc::new(sensor),
+            window,
+            samples: Arc::new(Mutex::new(VecDeque::with_capacity(window))),
+            monitor: MonitorState::default(),
+        }
+    }
+
+    /// Wrapped sensor
+    pub fn inner(&self) -> &S {
+        &self.sensor
+    }
+
+    // Add a reading to the window and return the current average
+    fn smooth(&self, data: Dht11Data) -> Dht11Data {
+        let mut samples = self.samples.lock().unwrap();
+        if samples.len() == self.window {
+            samples.pop_front();
+        }
+        samples.push_back(data);
+
+        let count = samples.len() as f32;
+        Dht11Data {
+            temperature: samples.iter().map(|s| s.temperature).sum::<f32>() / count,
+            humidity: samples.iter().map(|s| s.humidity).sum::<f32>() / count,
+            timestamp: data.timestamp,
+        }
+    }
+}
+
+#[async_trait]
+impl<S: TemperatureSensor + 'static> TemperatureSensor for Dht11Filtered<S> {
+    /// Read the wrapped sensor and return the smoothed reading
+    fn read(&self) -> Result<Dht11Data, SensorError> {
+        let data = self.sensor.read()?;
+        Ok(self.smooth(data))
+    }
+
+    /// Asynchronously read the wrapped sensor and return the smoothed reading
+    async fn read_async(&self) -> Result<Dht11Data, SensorError> {
+        let data = self.sensor.read_async().await?;
+        Ok(self.smooth(data))
+    }
+
+    /// Start monitoring the smoothed temperature against a high threshold
+    ///
+    /// The loop runs on the wrapper rather than the wrapped sensor, so each
+    /// reading goes through the window and a single noisy sample doesn't
+    /// alert on its own. The first read happens immediately.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::Dht11Data;
+    /// use env_monitor::sensors::TemperatureSensor;
+    /// use env_monitor::sensors::filtered::Dht11Filtered;
+    /// use env_monitor::sensors::mock::MockTemperatureSensor;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let at = |temperature| Dht11Data { temperature, humidity: 50.0, timestamp: 0 };
+    /// let sensor = Dht11Filtered::new(
+    ///     MockTemperatureSensor::new([at(20.0), at(20.0), at(50.0), at(20.0)]),
+    ///     2,
+    /// );
+    ///
+    /// let (tx, mut alerts) = tokio::sync::mpsc::unbounded_channel();
+    /// let handle = sensor
+    ///     .start_monitoring(1, 30.0, move |data| {
+    ///         let _ = tx.send(data.temperature);
+    ///     })
+    ///     .unwrap();
+    ///
+    /// // The 50°C spike alerts as its 35°C average with the sample before it
+    /// assert_eq!(alerts.recv().await, Some(35.0));
+    /// assert!(sensor.start_monitoring(1, 30.0, |_| {}).is_err());
+    ///
+    /// sensor.stop_monitoring();
+    /// handle.await.unwrap();
+    /// # });
+    /// # }
+    /// ```
+    fn start_monitoring<F>(
+        &self,
+        interval_ms: u64,
+        high_threshold: f32,
+        on_alert: F,
+    ) -> Result<JoinHandle<()>, SensorError>
+    where
+        F: Fn(Dht11Data) + Send + 'static,
+    {
+        self.monitor.spawn(
+            self.clone(),
+            interval_ms,
+            true,
+            high_threshold,
+            on_alert,
+            |_| {},
+        )
+    }
+
+    /// Stop temperature monitoring
+    fn stop_monitoring(&self) {
+        self.monitor.stop();
+    }
+}
@@ -1,80 +1,1263 @@
 //! Fire detection sensor implementation
 
 use async_trait::async_trait;
-use rppal::gpio::{Gpio, Level};
+use rppal::gpio::Level;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
-use tokio::task;
-use tokio::time::{Duration, sleep};
+use tokio::sync::Semaphore;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::task::{self, JoinHandle};
+use tokio::time::{Duration, Instant, sleep};
+use tokio_util::sync::CancellationToken;
 
+use crate::alarm::{MAX_TONE_FREQUENCY_HZ, idle_level, play_tone};
+pub use crate::clock::{ClockFallback, TimestampUnit};
 use crate::error::SensorError;
+use crate::events::{EventBus, SensorEvent};
+use crate::sensors::debounce::DebouncedInput;
+use crate::sensors::gpio::{GpioHandle, InputLine, OutputLine};
+use crate::sensors::monitoring::MonitorState;
+#[cfg(feature = "simulate")]
+use crate::sensors::simulate;
 use crate::sensors::traits::FireDetector;
+use crate::shutdown::ShutdownCoordinator;
+
+/// Monitoring check interval reported before monitoring is first started
+const DEFAULT_CHECK_INTERVAL_MS: u64 = 100;
 
 /// Fire sensor data structure containing detection status and timestamp
-#[derive(Debug, Clone, Copy)]
+///
+/// With the `serde` feature, `last_detection_timestamp` serializes as a
+/// nullable field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FireSensorData {
     /// Whether flame is detected
     pub flame_detected: bool,
-    /// Timestamp of the last detection (if detected)
+    /// Timestamp of the last detection (if detected), in the sensor's configured [`TimestampUnit`]
     pub last_detection_timestamp: Option<u64>,
 }
 
-/// Fire sensor implementation with buzzer support
-pub struct FireSensor {
-    /// GPIO pin number connected to the flame sensor
-    flame_pin: u8,
-    /// GPIO pin number connected to the buzzer
-    buzzer_pin: u8,
-    /// Sensor active state
-    is_active: Arc<Mutex<bool>>,
-    /// Sensor logic configuration (true = high level active, false = low level active)
-    high_active: bool,
-}
+impl FireSensorData {
+    /// Time of the last detection as a UTC date and time (requires the `chrono` feature)
+    ///
+    /// Assumes the timestamp is in seconds, the default [`TimestampUnit`].
+    /// Returns `None` if there was no detection or the timestamp is out of
+    /// range.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::FireSensorData;
+    ///
+    /// let data = FireSensorData { flame_detected: true, last_detection_timestamp: Some(1_700_000_000) };
+    /// let time = data.last_detection_datetime().unwrap();
+    /// assert_eq!(time.to_rfc3339(), "2023-11-14T22:13:20+00:00");
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn last_detection_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let secs = i64::try_from(self.last_detection_timestamp?).ok()?;
+        chrono::DateTime::from_timestamp(secs, 0)
+    }
+}
+
+/// Formats as `flame: detected @ <timestamp>` or `flame: clear`
+///
+/// # Example
+/// ```
+/// use env_monitor::FireSensorData;
+///
+/// let data = FireSensorData { flame_detected: true, last_detection_timestamp: Some(1_700_000_000) };
+/// assert_eq!(data.to_string(), "flame: detected @ 1700000000");
+///
+/// let data = FireSensorData { flame_detected: false, last_detection_timestamp: None };
+/// assert_eq!(data.to_string(), "flame: clear");
+/// ```
+impl fmt::Display for FireSensorData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.flame_detected, self.last_detection_timestamp) {
+            (true, Some(timestamp)) => write!(f, "flame: detected @ {}", timestamp),
+            (true, None) => write!(f, "flame: detected"),
+            (false, _) => write!(f, "flame: clear"),
+        }
+    }
+}
+
+/// Internal pull resistor applied to the flame sensor input
+///
+/// Without a pull resistor, on board or external, the input floats and reads
+/// erratically whenever the sensor's output is not actively driving it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pull {
+    /// Leave the input floating
+    #[default]
+    None,
+    /// Enable the internal pull-up resistor
+    Up,
+    /// Enable the internal pull-down resistor
+    Down,
+}
+
+// Flame sensor input, replaced by a scheduled flame with the `simulate` feature
+enum FlameInput {
+    Pin(InputLine),
+    #[cfg(feature = "simulate")]
+    Simulated(Arc<AtomicBool>),
+}
+
+impl FlameInput {
+    // Acquire the flame pin as an input, following the live sensor logic when simulated
+    fn open(
+        gpio: &GpioHandle,
+        flame_pin: u8,
+        pull: Pull,
+        high_active: &Arc<AtomicBool>,
+    ) -> Result<Self, SensorError> {
+        #[cfg(feature = "simulate")]
+        if gpio.is_simulated() {
+            return Ok(FlameInput::Simulated(high_active.clone()));
+        }
+
+        #[cfg(not(feature = "simulate"))]
+        let _ = high_active;
+        Ok(FlameInput::Pin(gpio.input(flame_pin, pull)?))
+    }
+
+    // Current logic level of the input
+    fn read(&self) -> Level {
+        match self {
+            FlameInput::Pin(pin) => pin.read(),
+            #[cfg(feature = "simulate")]
+            FlameInput::Simulated(high_active) => {
+                let high_active = high_active.load(Ordering::Relaxed);
+                Level::from(simulate::flame_present() == high_active)
+            }
+        }
+    }
+}
+
+/// Daily time window during which the alarm buzzer stays silent
+///
+/// Times are UTC time of day, since the crate has no time-zone database;
+/// convert local times before constructing the window. A window whose end is
+/// earlier than its start wraps around midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlackoutWindow {
+    /// Start of the window in seconds after UTC midnight (inclusive)
+    start_secs: u32,
+    /// End of the window in seconds after UTC midnight (exclusive)
+    end_secs: u32,
+}
+
+impl BlackoutWindow {
+    /// Create a window from `(hour, minute)` start and end times in UTC
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::BlackoutWindow;
+    ///
+    /// // 22:00 to 06:30 UTC, wrapping midnight
+    /// let window = BlackoutWindow::new((22, 0), (6, 30));
+    /// let day = 1_700_006_400; // a UTC midnight
+    /// assert!(window.contains(day + 23 * 3600));
+    /// assert!(window.contains(day + 6 * 3600));
+    /// assert!(!window.contains(day + 12 * 3600));
+    /// ```
+    pub fn new(start: (u8, u8), end: (u8, u8)) -> Self {
+        let to_secs =
+            |(hour, minute): (u8, u8)| (hour as u32 % 24) * 3600 + (minute as u32 % 60) * 60;
+        BlackoutWindow {
+            start_secs: to_secs(start),
+            end_secs: to_secs(end),
+        }
+    }
+
+    /// Whether the given Unix timestamp (seconds) falls inside the window
+    pub fn contains(&self, unix_secs: u64) -> bool {
+        let time_of_day = (unix_secs % 86_400) as u32;
+        if self.start_secs <= self.end_secs {
+            time_of_day >= self.start_secs && time_of_day < self.end_secs
+        } else {
+            time_of_day >= self.start_secs || time_of_day < self.end_secs
+        }
+    }
+
+    // Whether the current system time falls inside the window
+    fn is_active_now(&self) -> bool {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| self.contains(elapsed.as_secs()))
+            .unwrap_or(false)
+    }
+}
+
+/// Buzzer pin shared between the monitoring loop and the sensor
+///
+/// The loop installs its pin here so that dropping the sensor can silence the
+/// buzzer even while the loop is mid-tone.
+#[derive(Clone, Default)]
+struct SharedBuzzer(Arc<Mutex<Option<OutputLine>>>);
+
+impl SharedBuzzer {
+    fn install(&self, pin: OutputLine) {
+        *self.0.lock().unwrap() = Some(pin);
+    }
+
+    fn write(&self, level: Level) {
+        if let Some(pin) = self.0.lock().unwrap().as_mut() {
+            pin.write(level);
+        }
+    }
+
+    fn play_tone(&self, frequency_hz: u32, duration_ms: u64, idle: Level) {
+        if let Some(pin) = self.0.lock().unwrap().as_mut() {
+            play_tone(pin, frequency_hz, duration_ms, idle);
+        }
+    }
+
+    // Silence the buzzer and give the pin back
+    fn release(&self, idle: Level) {
+        if let Some(mut pin) = self.0.lock().unwrap().take() {
+            pin.write(idle);
+        }
+    }
+}
+
+/// Detection state published by the running monitoring loop
+///
+/// The loop holds the flame pin while it runs, so reads are served from here
+/// instead of opening the pin a second time. Each run publishes through its
+/// own [`LiveDetectionRun`], so a stopped loop exiting late can't clear the
+/// state of the run that replaced it.
+#[derive(Clone, Default)]
+struct LiveDetection(Arc<Mutex<LiveState>>);
+
+#[derive(Default)]
+struct LiveState {
+    /// Number of the latest run
+    run: u64,
+    /// Detection state of the latest run, None until it samples and once it exits
+    detected: Option<bool>,
+}
+
+impl LiveDetection {
+    fn get(&self) -> Option<bool> {
+        self.0.lock().unwrap().detected
+    }
+
+    // Start publishing for a new run, taking over from any previous one
+    fn start_run(&self) -> LiveDetectionRun {
+        let mut state = self.0.lock().unwrap();
+        state.run += 1;
+        state.detected = None;
+        LiveDetectionRun {
+            live: self.clone(),
+            run: state.run,
+        }
+    }
+}
+
+/// Publishing handle of one monitoring run, clearing the state when dropped
+struct LiveDetectionRun {
+    /// State shared with the sensor
+    live: LiveDetection,
+    /// Number of the run this handle publishes for
+    run: u64,
+}
+
+impl LiveDetectionRun {
+    fn set(&self, detected: bool) {
+        let mut state = self.live.0.lock().unwrap();
+        if state.run == self.run {
+            state.detected = Some(detected);
+        }
+    }
+}
+
+impl Drop for LiveDetectionRun {
+    fn drop(&mut self) {
+        let mut state = self.live.0.lock().unwrap();
+        if state.run == self.run {
+            state.detected = None;
+        }
+    }
+}
+
+/// Runtime mute and snooze state shared with the monitoring loop
+#[derive(Debug)]
+struct AlarmSilence {
+    /// Set by `mute`, cleared by `unmute`
+    muted: AtomicBool,
+    /// Reference point for `snoozed_until_ms`
+    epoch: Instant,
+    /// End of the current snooze in milliseconds after `epoch`
+    snoozed_until_ms: AtomicU64,
+}
+
+impl AlarmSilence {
+    fn new() -> Self {
+        AlarmSilence {
+            muted: AtomicBool::new(false),
+            epoch: Instant::now(),
+            snoozed_until_ms: AtomicU64::new(0),
+        }
+    }
+
+    fn is_snoozed(&self) -> bool {
+        (self.epoch.elapsed().as_millis() as u64) < self.snoozed_until_ms.load(Ordering::Relaxed)
+    }
+
+    // Whether the buzzer must stay silent right now
+    fn is_silenced(&self) -> bool {
+        self.muted.load(Ordering::Relaxed) || self.is_snoozed()
+    }
+}
+
+/// Fire sensor implementation with buzzer support
+///
+/// Dropping the sensor stops its monitoring loop and silences the buzzer, so
+/// keep it alive for as long as monitoring should run.
+pub struct FireSensor {
+    /// GPIO pin number connected to the flame sensor
+    flame_pin: u8,
+    /// GPIO pin number connected to the buzzer
+    buzzer_pin: u8,
+    /// Monitoring run state, stopped by `stop_monitoring`
+    monitor: MonitorState,
+    /// Monitoring check interval in milliseconds, read by the loop every iteration
+    check_interval_ms: Arc<AtomicU64>,
+    /// Sensor logic configuration (true = high level active, false = low level active)
+    high_active: Arc<AtomicBool>,
+    /// Pull resistor applied to the flame sensor input
+    pull: Pull,
+    /// Consecutive samples required to change detection state while monitoring
+    debounce_count: u32,
+    /// Whether monitoring checks immediately on start or after one interval
+    immediate_first: bool,
+    /// Time without flame before a detection event is considered cleared
+    coalesce_gap: Duration,
+    /// Daily window during which the buzzer stays silent
+    blackout: Option<BlackoutWindow>,
+    /// Runtime mute and snooze state, checked before sounding the buzzer
+    silence: Arc<AlarmSilence>,
+    /// Buzzer pin while a monitoring loop owns it
+    buzzer: SharedBuzzer,
+    /// Detection state of the running monitoring loop, served by reads
+    live: LiveDetection,
+    /// GPIO controller the pins are acquired from
+    gpio: GpioHandle,
+    /// Unit of the detection timestamps
+    timestamp_unit: TimestampUnit,
+    /// Behavior when the clock is before the Unix epoch
+    clock_fallback: ClockFallback,
+    /// Alarm tone frequency in Hz
+    alarm_frequency_hz: u32,
+    /// Duration of each alarm tone in milliseconds
+    alarm_duration_ms: u64,
+    /// Buzzer logic (true = sounds on high level, false = sounds on low level)
+    buzzer_active_high: bool,
+    /// Minimum wall-clock time the alarm sounds once started
+    min_alarm_duration: Duration,
+    /// Event bus that monitoring publishes flame transitions and faults to
+    event_bus: Option<EventBus>,
+    /// Callback invoked when a detected flame clears
+    on_clear: Option<ClearCallback>,
+    /// Coordinator that monitoring registers with for graceful shutdown
+    shutdown: Option<ShutdownCoordinator>,
+    /// Limits concurrent `read_async` calls (unbounded if None)
+    read_limiter: Option<Arc<Semaphore>>,
+}
+
+/// Callback invoked on flame clear with the duration of the detection
+pub type ClearCallback = Arc<dyn Fn(Duration) + Send + Sync>;
+
+/// Builder for a [`FireSensor`] with alarm tuning
+///
+/// Pins are required; the sensor logic defaults to high level active and the
+/// alarm tone to 1kHz for 200ms, as with [`FireSensor::new`]. The `with_*`
+/// setters on `FireSensor` remain available on the built sensor.
+///
+/// # Example
+/// ```
+/// use env_monitor::sensors::fire::FireSensorBuilder;
+///
+/// let sensor = FireSensorBuilder::new()
+///     .flame_pin(27)
+///     .buzzer_pin(22)
+///     .high_active(false)
+///     .alarm_frequency_hz(2500)
+///     .alarm_duration_ms(100)
+///     .build()
+///     .unwrap();
+/// assert!(!sensor.is_high_active());
+///
+/// // Both pins are required
+/// assert!(FireSensorBuilder::new().flame_pin(27).build().is_err());
+///
+/// // The tone must be audible and playable
+/// let builder = FireSensorBuilder::new().flame_pin(27).buzzer_pin(22);
+/// assert!(builder.clone().alarm_frequency_hz(0).build().is_err());
+/// assert!(builder.clone().alarm_frequency_hz(500_001).build().is_err());
+/// assert!(builder.alarm_frequency_hz(500_000).build().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FireSensorBuilder {
+    /// GPIO pin number connected to the flame sensor
+    flame_pin: Option<u8>,
+    /// GPIO pin number connected to the buzzer
+    buzzer_pin: Option<u8>,
+    /// Sensor logic configuration
+    high_active: bool,
+    /// Pull resistor applied to the flame sensor input
+    pull: Pull,
+    /// Buzzer logic configuration
+    buzzer_active_high: bool,
+    /// Alarm tone frequency in Hz
+    alarm_frequency_hz: u32,
+    /// Duration of each alarm tone in milliseconds
+    alarm_duration_ms: u64,
+}
+
+impl FireSensorBuilder {
+    /// Create a builder with default settings and no pins
+    pub fn new() -> Self {
+        FireSensorBuilder {
+            flame_pin: None,
+            buzzer_pin: None,
+            high_active: true,
+            pull: Pull::None,
+            buzzer_active_high: false,
+            alarm_frequency_hz: 1000, // 1kHz
+            alarm_duration_ms: 200,
+        }
+    }
+
+    /// GPIO pin number connected to the flame sensor
+    pub fn flame_pin(mut self, pin: u8) -> Self {
+        self.flame_pin = Some(pin);
+        self
+    }
+
+    /// GPIO pin number connected to the buzzer
+    pub fn buzzer_pin(mut self, pin: u8) -> Self {
+        self.buzzer_pin = Some(pin);
+        self
+    }
+
+    /// Sensor logic (true if high level indicates flame detection)
+    pub fn high_active(mut self, high_active: bool) -> Self {
+        self.high_active = high_active;
+        self
+    }
+
+    /// Pull resistor applied to the flame sensor input
+    pub fn pull(mut self, pull: Pull) -> Self {
+        self.pull = pull;
+        self
+    }
+
+    /// Buzzer logic (true if high level sounds the buzzer)
+    pub fn buzzer_active_high(mut self, active_high: bool) -> Self {
+        self.buzzer_active_high = active_high;
+        self
+    }
+
+    /// Alarm tone frequency in Hz
+    pub fn alarm_frequency_hz(mut self, frequency_hz: u32) -> Self {
+        self.alarm_frequency_hz = frequency_hz;
+        self
+    }
+
+    /// Duration of each alarm tone in milliseconds
+    pub fn alarm_duration_ms(mut self, duration_ms: u64) -> Self {
+        self.alarm_duration_ms = duration_ms;
+        self
+    }
+
+    /// Build the sensor
+    ///
+    /// # Returns
+    /// The sensor, or `SensorError::InitError` if a pin is missing or the
+    /// alarm frequency is outside 1Hz to
+    /// [`MAX_TONE_FREQUENCY_HZ`](crate::alarm::MAX_TONE_FREQUENCY_HZ) (500kHz)
+    pub fn build(self) -> Result<FireSensor, SensorError> {
+        let flame_pin = self
+            .flame_pin
+            .ok_or_else(|| SensorError::InitError("Flame pin not set".to_string()))?;
+        let buzzer_pin = self
+            .buzzer_pin
+            .ok_or_else(|| SensorError::InitError("Buzzer pin not set".to_string()))?;
+        if !(1..=MAX_TONE_FREQUENCY_HZ).contains(&self.alarm_frequency_hz) {
+            return Err(SensorError::InitError(format!(
+                "Alarm frequency must be between 1 and {} Hz, got {} Hz",
+                MAX_TONE_FREQUENCY_HZ, self.alarm_frequency_hz
+            )));
+        }
+
+        Ok(FireSensor::new(flame_pin, buzzer_pin, self.high_active)
+            .with_pull(self.pull)
+            .with_buzzer_active_high(self.buzzer_active_high)
+            .with_alarm_tone(self.alarm_frequency_hz, self.alarm_duration_ms))
+    }
+}
+
+impl Default for FireSensorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FireSensor {
+    /// Create a new fire sensor instance
+    ///
+    /// # Arguments
+    /// * `flame_pin` - GPIO pin number connected to the flame sensor
+    /// * `buzzer_pin` - GPIO pin number connected to the buzzer
+    /// * `high_active` - Sensor logic (true if high level indicates flame detection, false if low level indicates flame detection)
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// // If sensor outputs high level when flame is detected
+    /// let sensor = FireSensor::new(27, 17, true);
+    /// // If sensor outputs low level when flame is detected
+    /// let sensor = FireSensor::new(27, 17, false);
+    /// ```
+    pub fn new(flame_pin: u8, buzzer_pin: u8, high_active: bool) -> Self {
+        FireSensor {
+            flame_pin,
+            buzzer_pin,
+            monitor: MonitorState::default(),
+            check_interval_ms: Arc::new(AtomicU64::new(DEFAULT_CHECK_INTERVAL_MS)),
+            high_active: Arc::new(AtomicBool::new(high_active)),
+            pull: Pull::default(),
+            debounce_count: 1,
+            immediate_first: true,
+            coalesce_gap: Duration::ZERO,
+            blackout: None,
+            silence: Arc::new(AlarmSilence::new()),
+            buzzer: SharedBuzzer::default(),
+            live: LiveDetection::default(),
+            gpio: GpioHandle::default(),
+            timestamp_unit: TimestampUnit::default(),
+            clock_fallback: ClockFallback::default(),
+            alarm_frequency_hz: 1000, // 1kHz
+            alarm_duration_ms: 200,
+            buzzer_active_high: false,
+            min_alarm_duration: Duration::ZERO,
+            event_bus: None,
+            on_clear: None,
+            shutdown: None,
+            read_limiter: None,
+        }
+    }
+
+    /// Limit how many `read_async` calls may be in flight at once
+    ///
+    /// A read beyond `max_pending` fails immediately with
+    /// `SensorError::SensorError("read queue full")` rather than queueing on a
+    /// saturated blocking pool. Unlimited by default, and a limit of 0 also
+    /// means unlimited.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true).with_max_pending_reads(2);
+    /// ```
+    ///
+    /// Against a `MockGpio` (with the `mock` feature):
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use env_monitor::sensors::mock::MockGpio;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let sensor = FireSensor::new(27, 22, true)
+    ///     .with_max_pending_reads(0)
+    ///     .with_gpio(MockGpio::new());
+    /// assert!(sensor.read_async().await.is_ok());
+    /// # });
+    /// # }
+    /// ```
+    pub fn with_max_pending_reads(mut self, max_pending: usize) -> Self {
+        self.read_limiter = (max_pending > 0).then(|| Arc::new(Semaphore::new(max_pending)));
+        self
+    }
+
+    /// Acquire the pins from the given GPIO controller
+    ///
+    /// Accepts an opened `rppal::gpio::Gpio` (or `Arc<Gpio>`) to share one
+    /// controller between sensors, or a `MockGpio` with the `mock` feature. By
+    /// default the sensor opens the controller itself on first use.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use rppal::gpio::Level;
+    ///
+    /// let gpio = MockGpio::new();
+    /// gpio.set_level(27, Level::Low);
+    /// let sensor = FireSensor::new(27, 22, false).with_gpio(gpio);
+    /// assert!(sensor.read().unwrap().flame_detected);
+    /// # }
+    /// ```
+    pub fn with_gpio(mut self, gpio: impl Into<GpioHandle>) -> Self {
+        self.gpio = gpio.into();
+        self
+    }
+
+    /// Run an action whenever a detected flame clears while monitoring
+    ///
+    /// The callback receives how long the flame was detected. It is invoked
+    /// once per clear, only after a detection, never at startup. The buzzer is
+    /// silenced before the callback runs, so it may safely take time (e.g. to
+    /// reset a sprinkler relay); it runs on the monitoring task and delays the
+    /// next check until it returns.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true).with_on_clear(|duration| {
+    ///     println!("All clear after {:?}", duration);
+    /// });
+    /// ```
+    pub fn with_on_clear<F>(mut self, on_clear: F) -> Self
+    where
+        F: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.on_clear = Some(Arc::new(on_clear));
+        self
+    }
+
+    /// Register monitoring with a shutdown coordinator
+    ///
+    /// Each `start_monitoring` call registers its loop, so
+    /// `ShutdownCoordinator::shutdown` stops it alongside every other
+    /// registered monitor and returns only after the buzzer has been silenced
+    /// and the loop has exited. `stop_monitoring` keeps working independently.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use env_monitor::shutdown::ShutdownCoordinator;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let coordinator = ShutdownCoordinator::new();
+    ///     let kitchen = FireSensor::new(27, 17, true).with_shutdown(coordinator.clone());
+    ///     let garage = FireSensor::new(5, 6, true).with_shutdown(coordinator.clone());
+    ///     kitchen.start_monitoring(100).await?;
+    ///     garage.start_monitoring(100).await?;
+    ///
+    ///     tokio::signal::ctrl_c().await?;
+    ///     coordinator.shutdown().await;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_shutdown(mut self, coordinator: ShutdownCoordinator) -> Self {
+        self.shutdown = Some(coordinator);
+        self
+    }
+
+    /// Enable an internal pull resistor on the flame sensor input
+    ///
+    /// Use this on boards without an external pull resistor, where the input
+    /// would otherwise float; pick the pull towards the no-flame level (up for
+    /// a low level active sensor, down for a high level active one). Applies to
+    /// reads, polling and interrupt monitoring. No pull by default.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::{FireSensor, Pull};
+    ///
+    /// let sensor = FireSensor::new(27, 22, false).with_pull(Pull::Up);
+    /// ```
+    pub fn with_pull(mut self, pull: Pull) -> Self {
+        self.pull = pull;
+        self
+    }
+
+    /// Set the buzzer logic level
+    ///
+    /// Buzzers are assumed active-low by default: driving the pin low sounds
+    /// them and high silences them. Pass `true` for an active-high buzzer
+    /// module. The level applies to the idle state, alarm tones, melodies and
+    /// the self-test.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use rppal::gpio::Level;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true);
+    /// assert_eq!(sensor.buzzer_idle_level(), Level::High);
+    ///
+    /// let sensor = sensor.with_buzzer_active_high(true);
+    /// assert_eq!(sensor.buzzer_idle_level(), Level::Low);
+    /// ```
+    pub fn with_buzzer_active_high(mut self, active_high: bool) -> Self {
+        self.buzzer_active_high = active_high;
+        self
+    }
+
+    /// Level the buzzer pin is held at while silent
+    pub fn buzzer_idle_level(&self) -> Level {
+        idle_level(self.buzzer_active_high)
+    }
+
+    /// GPIO pin number of the flame sensor
+    pub fn flame_pin(&self) -> u8 {
+        self.flame_pin
+    }
+
+    /// GPIO pin number of the buzzer
+    pub fn buzzer_pin(&self) -> u8 {
+        self.buzzer_pin
+    }
+
+    /// Keep the alarm sounding for at least `duration` once it starts
+    ///
+    /// A flame flickering at the debounce boundary can otherwise produce brief
+    /// chirps. Unlike [`with_debounce`](FireSensor::with_debounce), which
+    /// delays silencing by a number of reads, this guarantees a wall-clock
+    /// minimum: the buzzer keeps sounding after the flame clears until
+    /// `duration` has passed since it started. It only holds the buzzer; the
+    /// `FlameCleared` event and the clear callback still fire as soon as the
+    /// detection clears. A blackout window, mute or snooze silences the alarm
+    /// regardless of the hold. Disabled (zero) by default.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use std::time::Duration;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true).with_min_alarm_duration(Duration::from_secs(3));
+    /// ```
+    pub fn with_min_alarm_duration(mut self, duration: Duration) -> Self {
+        self.min_alarm_duration = duration;
+        self
+    }
+
+    /// Publish monitoring events to the given event bus
+    ///
+    /// While monitoring, a `FlameDetected` or `FlameCleared` event is published
+    /// on each change of detection state, and a `Fault` event if the GPIO pins
+    /// fail to initialize.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::events::EventBus;
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let bus = EventBus::new(16);
+    /// let sensor = FireSensor::new(27, 22, true).with_event_bus(bus.clone());
+    /// let mut events = bus.subscribe();
+    /// ```
+    pub fn with_event_bus(mut self, bus: EventBus) -> Self {
+        self.event_bus = Some(bus);
+        self
+    }
+
+    /// Require several consecutive agreeing samples before monitoring changes state
+    ///
+    /// With the default of 1, each check is taken at face value. Larger counts
+    /// filter out single-sample glitches at the cost of `count - 1` extra check
+    /// intervals of latency.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true).with_debounce(3);
+    /// ```
+    pub fn with_debounce(mut self, debounce_count: u32) -> Self {
+        self.debounce_count = debounce_count;
+        self
+    }
+
+    /// Choose whether monitoring checks immediately or waits one interval first
+    ///
+    /// Fire monitoring checks immediately by default, since a flame present at
+    /// startup must not go unnoticed for a whole interval. Disable this for
+    /// flame modules that need a moment to settle after power-up.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true).with_immediate_first(false);
+    /// ```
+    pub fn with_immediate_first(mut self, immediate_first: bool) -> Self {
+        self.immediate_first = immediate_first;
+        self
+    }
+
+    /// Merge rapid flame flicker into a single sustained detection event
+    ///
+    /// Once a flame is detected, the detection is only reported as cleared
+    /// (`FlameCleared` event and clear callback) after `gap` passes with no
+    /// detection; flame reappearing within the gap continues the same event.
+    /// This consolidates events and is independent of debouncing, which
+    /// filters individual samples. The buzzer still follows each check. The
+    /// default gap of zero reports a clear as soon as it is observed.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use std::time::Duration;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true).with_coalesce_gap(Duration::from_secs(5));
+    /// ```
+    pub fn with_coalesce_gap(mut self, gap: Duration) -> Self {
+        self.coalesce_gap = gap;
+        self
+    }
+
+    /// Keep the buzzer silent during a daily blackout window
+    ///
+    /// Detection continues as normal: events and callbacks still fire, and a
+    /// flame detected during the window additionally publishes an
+    /// `AlarmSuppressed` event so consumers know the buzzer stayed silent.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::{BlackoutWindow, FireSensor};
+    ///
+    /// let sensor = FireSensor::new(27, 22, true)
+    ///     .with_blackout(BlackoutWindow::new((8, 0), (12, 0)));
+    /// ```
+    pub fn with_blackout(mut self, window: BlackoutWindow) -> Self {
+        self.blackout = Some(window);
+        self
+    }
+
+    /// Set the behavior when the system clock is before the Unix epoch
+    ///
+    /// By default a detection read falls back to timestamp `0` with a warning
+    /// instead of failing, so a clock adjustment cannot hide a flame.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::{ClockFallback, FireSensor};
+    ///
+    /// let sensor = FireSensor::new(27, 22, true).with_clock_fallback(ClockFallback::Error);
+    /// ```
+    pub fn with_clock_fallback(mut self, fallback: ClockFallback) -> Self {
+        self.clock_fallback = fallback;
+        self
+    }
+
+    /// Set the alarm tone sounded on the buzzer (1kHz for 200ms by default)
+    ///
+    /// A frequency of 0 keeps the buzzer silent; frequencies above
+    /// [`MAX_TONE_FREQUENCY_HZ`](crate::alarm::MAX_TONE_FREQUENCY_HZ) (500kHz)
+    /// are clamped to it.
+    ///
+    /// # Arguments
+    /// * `frequency_hz` - Tone frequency in Hz
+    /// * `duration_ms` - Duration of each tone in milliseconds
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true).with_alarm_tone(2000, 100);
+    /// ```
+    ///
+    /// A silent tone leaves the buzzer idle while a flame is detected (with
+    /// the `mock` feature):
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use rppal::gpio::Level;
+    /// use std::time::Duration;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let gpio = MockGpio::new();
+    /// gpio.set_level(27, Level::High);
+    /// let sensor = FireSensor::new(27, 22, true)
+    ///     .with_alarm_tone(0, 20)
+    ///     .with_gpio(gpio.clone());
+    ///
+    /// let handle = sensor.start_monitoring(10).await.unwrap();
+    /// tokio::time::sleep(Duration::from_millis(100)).await;
+    /// sensor.stop_monitoring();
+    /// handle.await.unwrap();
+    ///
+    /// let idle = sensor.buzzer_idle_level();
+    /// assert!(gpio.writes(22).iter().all(|&(_, level)| level == idle));
+    /// # });
+    /// # }
+    /// ```
+    pub fn with_alarm_tone(mut self, frequency_hz: u32, duration_ms: u64) -> Self {
+        self.alarm_frequency_hz = frequency_hz;
+        self.alarm_duration_ms = duration_ms;
+        self
+    }
+
+    /// Set the unit used for detection timestamps (seconds by default)
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::{FireSensor, TimestampUnit};
+    ///
+    /// let sensor = FireSensor::new(27, 22, true).with_timestamp_unit(TimestampUnit::Millis);
+    /// ```
+    pub fn with_timestamp_unit(mut self, unit: TimestampUnit) -> Self {
+        self.timestamp_unit = unit;
+        self
+    }
+
+    /// Current monitoring check interval in milliseconds
+    ///
+    /// This is the interval last passed to `start_monitoring` or
+    /// [`set_check_interval`](FireSensor::set_check_interval), or 100 ms
+    /// before either is called.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true);
+    /// assert_eq!(sensor.check_interval(), 100);
+    /// ```
+    pub fn check_interval(&self) -> u64 {
+        self.check_interval_ms.load(Ordering::Relaxed)
+    }
+
+    /// Change the monitoring check interval without restarting monitoring
+    ///
+    /// The running loop picks up the new value on its next sleep, so the change
+    /// takes effect with up to one cycle of the previous interval's latency.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let sensor = FireSensor::new(27, 22, true);
+    ///     sensor.start_monitoring(100).await?;
+    ///
+    ///     // Poll less often at night
+    ///     sensor.set_check_interval(1000);
+    ///     assert_eq!(sensor.check_interval(), 1000);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Against a `MockGpio` (with the `mock` feature), the running loop slows
+    /// down without being restarted:
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use std::time::Duration;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let gpio = MockGpio::new();
+    /// let sensor = FireSensor::new(27, 22, true).with_gpio(gpio.clone());
+    /// sensor.start_monitoring(10).await.unwrap();
+    ///
+    /// tokio::time::sleep(Duration::from_millis(200)).await;
+    /// assert!(gpio.reads(27) >= 5);
+    ///
+    /// // Let the loop finish its current 10 ms sleep, then count reads
+    /// sensor.set_check_interval(1000);
+    /// tokio::time::sleep(Duration::from_millis(50)).await;
+    /// let reads = gpio.reads(27);
+    /// tokio::time::sleep(Duration::from_millis(300)).await;
+    /// assert!(gpio.reads(27) - reads <= 1);
+    /// # });
+    /// # }
+    /// ```
+    pub fn set_check_interval(&self, check_interval_ms: u64) {
+        self.check_interval_ms
+            .store(check_interval_ms, Ordering::Relaxed);
+    }
+
+    /// Silence the buzzer for `duration` without stopping monitoring
+    ///
+    /// The running loop keeps reading the sensor and publishing events while
+    /// snoozed (a detection publishes `AlarmSuppressed` as in a blackout
+    /// window); only the buzzer stays silent. The snooze expires on its own
+    /// after `duration`. Snoozing again replaces the remaining time, so
+    /// `snooze(Duration::ZERO)` ends a snooze early.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use std::time::Duration;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true);
+    /// sensor.snooze(Duration::from_secs(60));
+    /// assert!(sensor.is_snoozed());
+    ///
+    /// sensor.snooze(Duration::ZERO);
+    /// assert!(!sensor.is_snoozed());
+    /// ```
+    pub fn snooze(&self, duration: Duration) {
+        let until = self.silence.epoch.elapsed().saturating_add(duration);
+        self.silence
+            .snoozed_until_ms
+            .store(until.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Whether a snooze is currently in effect
+    pub fn is_snoozed(&self) -> bool {
+        self.silence.is_snoozed()
+    }
+
+    /// Silence the buzzer until [`unmute`](FireSensor::unmute) is called
+    ///
+    /// Like [`snooze`](FireSensor::snooze), detection and events carry on.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true);
+    /// sensor.mute();
+    /// assert!(sensor.is_muted());
+    /// sensor.unmute();
+    /// assert!(!sensor.is_muted());
+    /// ```
+    pub fn mute(&self) {
+        self.silence.muted.store(true, Ordering::Relaxed);
+    }
+
+    /// Let the buzzer sound again after [`mute`](FireSensor::mute)
+    ///
+    /// A snooze still in effect keeps the buzzer silent until it expires.
+    pub fn unmute(&self) {
+        self.silence.muted.store(false, Ordering::Relaxed);
+    }
+
+    /// Whether the buzzer is muted
+    pub fn is_muted(&self) -> bool {
+        self.silence.muted.load(Ordering::Relaxed)
+    }
+
+    /// Play a sequence of tones on the buzzer, e.g. a short melody
+    ///
+    /// Each entry is a `(frequency_hz, duration_ms)` pair; a frequency of `0` is
+    /// a rest of the given duration. This blocks for the total duration and
+    /// leaves the buzzer idle afterwards.
+    ///
+    /// # Returns
+    /// `SensorError::SensorError` without playing anything if a frequency is
+    /// above [`MAX_TONE_FREQUENCY_HZ`](crate::alarm::MAX_TONE_FREQUENCY_HZ)
+    /// (500kHz)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true);
+    /// // Two-tone chime with a short pause
+    /// sensor.play_sequence(&[(880, 150), (0, 50), (660, 300)]).unwrap();
+    /// ```
+    ///
+    /// Against a `MockGpio` (with the `mock` feature):
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use std::time::Duration;
+    ///
+    /// let gpio = MockGpio::new();
+    /// let sensor = FireSensor::new(27, 22, true).with_gpio(gpio.clone());
+    /// let idle = sensor.buzzer_idle_level();
+    ///
+    /// // 20 cycles at 1kHz, a 30ms rest, then 10 cycles at 500Hz
+    /// sensor.play_sequence(&[(1000, 20), (0, 30), (500, 20)]).unwrap();
+    ///
+    /// let writes = gpio.writes(22);
+    /// assert_eq!(writes.iter().filter(|&&(_, level)| level != idle).count(), 30);
+    /// assert_eq!(writes.last().unwrap().1, idle);
+    ///
+    /// let times: Vec<_> = writes.iter().map(|&(time, _)| time).collect();
+    /// assert!(*times.last().unwrap() - times[0] >= Duration::from_millis(70));
+    /// let longest_gap = times.windows(2).map(|w| w[1] - w[0]).max().unwrap();
+    /// assert!(longest_gap >= Duration::from_millis(30));
+    ///
+    /// // An unplayable note is rejected before anything sounds
+    /// assert!(sensor.play_sequence(&[(880, 10), (600_000, 10)]).is_err());
+    /// assert_eq!(gpio.writes(22).len(), writes.len());
+    /// # }
+    /// ```
+    pub fn play_sequence(&self, notes: &[(u32, u64)]) -> Result<(), SensorError> {
+        if let Some(&(frequency_hz, _)) = notes
+            .iter()
+            .find(|&&(frequency_hz, _)| frequency_hz > MAX_TONE_FREQUENCY_HZ)
+        {
+            return Err(SensorError::SensorError(format!(
+                "Note frequency {} Hz is above the {} Hz maximum",
+                frequency_hz, MAX_TONE_FREQUENCY_HZ
+            )));
+        }
+
+        let mut buzzer = self.gpio.output(self.buzzer_pin)?;
+        let idle = self.buzzer_idle_level();
+
+        buzzer.write(idle);
+        for &(frequency_hz, duration_ms) in notes {
+            // A frequency of 0 rests with the buzzer idle
+            play_tone(&mut buzzer, frequency_hz, duration_ms, idle);
+        }
+
+        // Return the buzzer to idle
+        buzzer.write(idle);
+
+        Ok(())
+    }
+
+    /// Verify the buzzer pin responds by toggling it and reading the level back
+    ///
+    /// The pin is driven high and low in turn and its level register is read
+    /// after each write. This assumes the buzzer is wired directly to the GPIO
+    /// pin (or through a driver that doesn't clamp the line), so the pad follows
+    /// the output; a shorted or overloaded line fails to change level. The buzzer
+    /// sounds briefly during the test and is left idle afterwards.
+    ///
+    /// # Returns
+    /// `true` if the pin followed both writes, `false` if the level didn't change
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true);
+    /// match sensor.self_test_buzzer() {
+    ///     Ok(true) => println!("Buzzer OK"),
+    ///     Ok(false) => println!("Buzzer pin did not respond, check wiring"),
+    ///     Err(e) => println!("Self-test failed: {}", e),
+    /// }
+    /// ```
+    pub fn self_test_buzzer(&self) -> Result<bool, SensorError> {
+        const SETTLE_TIME: std::time::Duration = std::time::Duration::from_millis(50);
+
+        let mut buzzer = self.gpio.output(self.buzzer_pin)?;
+
+        buzzer.write(Level::High);
+        std::thread::sleep(SETTLE_TIME);
+        let high_ok = buzzer.level() == Level::High;
+
+        buzzer.write(Level::Low);
+        std::thread::sleep(SETTLE_TIME);
+        let low_ok = buzzer.level() == Level::Low;
+
+        // Return the buzzer to idle
+        buzzer.write(self.buzzer_idle_level());
+
+        Ok(high_ok && low_ok)
+    }
+
+    /// Verify both pins can be acquired and the buzzer responds
+    ///
+    /// Claims the flame pin as an input, then runs
+    /// [`self_test_buzzer`](FireSensor::self_test_buzzer), so the buzzer sounds
+    /// briefly. Any failure is reported as `SensorError::InitError` so
+    /// deployments can fail fast on startup.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true);
+    /// if let Err(e) = sensor.self_test() {
+    ///     eprintln!("{}", e);
+    ///     std::process::exit(1);
+    /// }
+    /// ```
+    pub fn self_test(&self) -> Result<(), SensorError> {
+        let fail = |reason: String| {
+            SensorError::InitError(format!("Fire sensor self-test failed: {}", reason))
+        };
 
-impl FireSensor {
-    /// Create a new fire sensor instance
+        self.gpio
+            .input(self.flame_pin, self.pull)
+            .map_err(|e| fail(format!("flame pin {}: {}", self.flame_pin, e)))?;
+
+        match self.self_test_buzzer() {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(fail(format!(
+                "buzzer pin {} did not follow writes",
+                self.buzzer_pin
+            ))),
+            Err(e) => Err(fail(format!("buzzer pin {}: {}", self.buzzer_pin, e))),
+        }
+    }
+
+    /// Whether the sensor is configured as high level active
+    pub fn is_high_active(&self) -> bool {
+        self.high_active.load(Ordering::Relaxed)
+    }
+
+    /// Change the sensor logic (true if high level indicates flame detection)
     ///
-    /// # Arguments
-    /// * `flame_pin` - GPIO pin number connected to the flame sensor
-    /// * `buzzer_pin` - GPIO pin number connected to the buzzer
-    /// * `high_active` - Sensor logic (true if high level indicates flame detection, false if low level indicates flame detection)
+    /// Intended for setup wizards: flip the setting live while watching
+    /// [`read_raw_level`](FireSensor::read_raw_level) to find the correct one.
+    /// A running monitoring loop picks up the change on its next iteration.
     ///
     /// # Example
     /// ```
     /// use env_monitor::sensors::fire::FireSensor;
     ///
-    /// // If sensor outputs high level when flame is detected
-    /// let sensor = FireSensor::new(27, 17, true);
-    /// // If sensor outputs low level when flame is detected
-    /// let sensor = FireSensor::new(27, 17, false);
+    /// let sensor = FireSensor::new(27, 22, true);
+    /// sensor.set_high_active(false);
+    /// assert!(!sensor.is_high_active());
     /// ```
-    pub fn new(flame_pin: u8, buzzer_pin: u8, high_active: bool) -> Self {
-        FireSensor {
-            flame_pin,
-            buzzer_pin,
-            is_active: Arc::new(Mutex::new(true)),
-            high_active,
-        }
+    pub fn set_high_active(&self, high_active: bool) {
+        self.high_active.store(high_active, Ordering::Relaxed);
+    }
+
+    /// Read the flame pin's raw logic level, independent of the sensor logic setting
+    ///
+    /// Fails while a monitoring loop holds the flame pin; use `read` instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// let sensor = FireSensor::new(27, 22, true);
+    /// println!("Flame pin level: {:?}", sensor.read_raw_level());
+    /// ```
+    pub fn read_raw_level(&self) -> Result<Level, SensorError> {
+        Ok(FlameInput::open(&self.gpio, self.flame_pin, self.pull, &self.high_active)?.read())
     }
 
     // Helper function for reading sensor status
     fn read_internal(&self) -> Result<FireSensorData, SensorError> {
-        let gpio = Gpio::new()?;
-        let flame_sensor = gpio.get(self.flame_pin)?.into_input();
+        let flame_detected = match self.live.get() {
+            Some(detected) => detected,
+            None => {
+                let flame_sensor =
+                    FlameInput::open(&self.gpio, self.flame_pin, self.pull, &self.high_active)?;
 
-        // Determine flame detection based on configuration
-        let flame_detected = if self.high_active {
-            flame_sensor.read() == Level::High
-        } else {
-            flame_sensor.read() == Level::Low
+                // Determine flame detection based on configuration
+                if self.is_high_active() {
+                    flame_sensor.read() == Level::High
+                } else {
+                    flame_sensor.read() == Level::Low
+                }
+            }
         };
 
         let timestamp = if flame_detected {
-            Some(
-                std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .map_err(|e| SensorError::SensorError(format!("Time error: {}", e)))?
-                    .as_secs(),
-            )
+            Some(self.timestamp_unit.now(self.clock_fallback)?)
         } else {
             None
         };
@@ -84,12 +1267,447 @@ impl FireSensor {
             last_detection_timestamp: timestamp,
         })
     }
+
+    /// Start monitoring and send detection state changes over a channel
+    ///
+    /// Behaves like [`FireDetector::start_monitoring`], and additionally sends
+    /// a `FireSensorData` on every edge (no flame → flame and back), never on
+    /// every check, so the channel is not flooded. Sending never blocks the
+    /// monitoring loop: if the channel is full the event is dropped with a
+    /// warning, and if the receiver is gone monitoring simply continues.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use tokio::sync::mpsc;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let sensor = FireSensor::new(27, 17, true);
+    ///     let (tx, mut rx) = mpsc::channel(16);
+    ///     sensor.start_monitoring_with_sender(100, tx).await?;
+    ///
+    ///     while let Some(data) = rx.recv().await {
+    ///         println!("Flame detected: {}", data.flame_detected);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn start_monitoring_with_sender(
+        &self,
+        check_interval_ms: u64,
+        tx: mpsc::Sender<FireSensorData>,
+    ) -> Result<JoinHandle<()>, SensorError> {
+        self.spawn_monitoring(check_interval_ms, Some(tx), None)
+    }
+
+    /// Start monitoring that stops as soon as `token` is cancelled
+    ///
+    /// `stop_monitoring` is only noticed once per check interval, while
+    /// cancelling the token also wakes the loop from its sleep, so the buzzer
+    /// is silenced and the task exits without waiting for the next check (a
+    /// tone already playing finishes first). `stop_monitoring` keeps working.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use tokio_util::sync::CancellationToken;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let sensor = FireSensor::new(27, 17, true);
+    ///     let token = CancellationToken::new();
+    ///     let handle = sensor.start_monitoring_with_token(5000, token.clone()).await?;
+    ///
+    ///     // Do other things...
+    ///
+    ///     token.cancel();
+    ///     handle.await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn start_monitoring_with_token(
+        &self,
+        check_interval_ms: u64,
+        token: CancellationToken,
+    ) -> Result<JoinHandle<()>, SensorError> {
+        self.spawn_monitoring(check_interval_ms, None, Some(token))
+    }
+
+    /// Start interrupt-driven monitoring instead of polling the flame pin
+    ///
+    /// The flame pin is watched with a GPIO edge interrupt on both edges, so
+    /// no CPU is spent polling and a flicker shorter than the polling interval
+    /// is still caught. The buzzer starts as soon as the active edge arrives
+    /// (rising for high level active, falling otherwise) and keeps sounding
+    /// until the opposite edge. `FlameDetected`/`FlameCleared` events, the
+    /// clear callback, blackout window, mute and snooze work as in polling
+    /// mode; the sample-count debounce, coalesce gap and minimum alarm time
+    /// only apply to polling. Stop it with `stop_monitoring` like the polling version.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::fire::FireSensor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let sensor = FireSensor::new(27, 17, true);
+    ///     let handle = sensor.start_monitoring_interrupt().await?;
+    ///
+    ///     // Do other things...
+    ///
+    ///     sensor.stop_monitoring();
+    ///     handle.await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn start_monitoring_interrupt(&self) -> Result<JoinHandle<()>, SensorError> {
+        // How often an idle loop checks whether it has been stopped
+        const STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+        log::info!("Starting interrupt-driven fire monitoring");
+
+        let run = self.monitor.begin()?;
+        let high_active = self.is_high_active();
+        let active_level = if high_active { Level::High } else { Level::Low };
+
+        // The interrupt callback runs on rppal's thread; forward edges to the task
+        let (edge_tx, mut edge_rx) = mpsc::unbounded_channel();
+        let pins = self
+            .gpio
+            .input(self.flame_pin, self.pull)
+            .and_then(|mut flame_sensor| {
+                flame_sensor.watch_edges(move |level| {
+                    let _ = edge_tx.send(level == active_level);
+                })?;
+                Ok((flame_sensor, self.gpio.output(self.buzzer_pin)?))
+            });
+        let (flame_sensor, buzzer_pin) = match pins {
+            Ok(pins) => pins,
+            Err(e) => {
+                run.cancel();
+                return Err(e);
+            }
+        };
+        let mut flame_detected = flame_sensor.read() == active_level;
+        let buzzer_idle = self.buzzer_idle_level();
+        let alarm_frequency_hz = self.alarm_frequency_hz;
+        let alarm_duration_ms = self.alarm_duration_ms;
+        let timestamp_unit = self.timestamp_unit;
+        let clock_fallback = self.clock_fallback;
+        let event_bus = self.event_bus.clone();
+        let on_clear = self.on_clear.clone();
+        let blackout = self.blackout;
+        let silence = self.silence.clone();
+        let shutdown = self.shutdown.as_ref().map(|c| c.register());
+        let buzzer = self.buzzer.clone();
+        buzzer.install(buzzer_pin);
+        let live = self.live.start_run();
+        live.set(flame_detected);
+
+        Ok(tokio::spawn(async move {
+            // Released in reverse order on exit: the flame pin first, then the
+            // published state, and finally the run so monitoring can restart
+            let _finished = run.clone().drop_guard();
+            let live = live;
+            // Keeps the interrupt registered for the life of the task
+            let _flame_sensor = flame_sensor;
+            let publish = |event: SensorEvent| {
+                if let Some(bus) = &event_bus {
+                    bus.publish(event);
+                }
+            };
+            let silenced = || silence.is_silenced() || blackout.is_some_and(|w| w.is_active_now());
+
+            buzzer.write(buzzer_idle);
+            let mut was_detected = false;
+            let mut detected_since = Instant::now();
+
+            while !run.is_cancelled() && !shutdown.as_ref().is_some_and(|h| h.is_shutdown()) {
+                // Apply edges that arrived while the alarm tone was playing
+                while let Ok(detected) = edge_rx.try_recv() {
+                    flame_detected = detected;
+                }
+                live.set(flame_detected);
+
+                if flame_detected != was_detected {
+                    was_detected = flame_detected;
+                    let data = FireSensorData {
+                        flame_detected,
+                        last_detection_timestamp: if flame_detected {
+                            timestamp_unit.now(clock_fallback).ok()
+                        } else {
+                            None
+                        },
+                    };
+                    if flame_detected {
+                        log::warn!("Flame detected!");
+                        detected_since = Instant::now();
+                        publish(SensorEvent::FlameDetected(data));
+                        if silenced() {
+                            publish(SensorEvent::AlarmSuppressed(data));
+                        }
+                    } else {
+                        buzzer.write(buzzer_idle); // Silence before running the clear action
+                        publish(SensorEvent::FlameCleared(data));
+                        if let Some(on_clear) = &on_clear {
+                            on_clear(detected_since.elapsed());
+                        }
+                    }
+                }
+
+                if flame_detected && !silenced() {
+                    buzzer.play_tone(alarm_frequency_hz, alarm_duration_ms, buzzer_idle);
+                    tokio::task::yield_now().await;
+                } else {
+                    buzzer.write(buzzer_idle);
+                    // Sleep until the next edge, waking periodically to check for stop
+                    tokio::select! {
+                        Some(detected) = edge_rx.recv() => flame_detected = detected,
+                        _ = run.cancelled() => {}
+                        _ = sleep(STOP_POLL_INTERVAL) => {}
+                    }
+                }
+            }
+
+            buzzer.release(buzzer_idle); // Ensure buzzer is off
+        }))
+    }
+
+    // Acquire the flame input and, unless simulated, the buzzer for a monitoring loop
+    //
+    // Failures are also published as `Fault` events.
+    fn acquire_monitoring_pins(&self) -> Result<(FlameInput, Option<OutputLine>), SensorError> {
+        let fault = |what: &str, e: SensorError| {
+            log::error!("Failed to initialize {}: {}", what, e);
+            if let Some(bus) = &self.event_bus {
+                bus.publish(SensorEvent::Fault(format!(
+                    "Failed to initialize {}: {}",
+                    what, e
+                )));
+            }
+            e
+        };
+
+        let flame_sensor =
+            FlameInput::open(&self.gpio, self.flame_pin, self.pull, &self.high_active)
+                .map_err(|e| fault("flame sensor", e))?;
+        let buzzer = if self.gpio.is_simulated() {
+            None
+        } else {
+            Some(
+                self.gpio
+                    .output(self.buzzer_pin)
+                    .map_err(|e| fault("buzzer", e))?,
+            )
+        };
+        Ok((flame_sensor, buzzer))
+    }
+
+    // Spawn the monitoring loop, optionally sending edges to a channel
+    fn spawn_monitoring(
+        &self,
+        check_interval_ms: u64,
+        edge_tx: Option<mpsc::Sender<FireSensorData>>,
+        cancel: Option<CancellationToken>,
+    ) -> Result<JoinHandle<()>, SensorError> {
+        log::info!("Starting fire monitoring");
+        log::info!(
+            "Sensor configuration: {} level active",
+            if self.is_high_active() { "high" } else { "low" }
+        );
+
+        self.set_check_interval(check_interval_ms);
+
+        let run = self.monitor.begin()?;
+        let (flame_sensor, buzzer_pin) = match self.acquire_monitoring_pins() {
+            Ok(pins) => pins,
+            Err(e) => {
+                run.cancel();
+                return Err(e);
+            }
+        };
+
+        let check_interval_clone = self.check_interval_ms.clone();
+        let high_active = self.high_active.clone();
+        let mut flame_input = DebouncedInput::new(self.is_high_active(), self.debounce_count);
+        let alarm_frequency_hz = self.alarm_frequency_hz;
+        let alarm_duration_ms = self.alarm_duration_ms;
+        let min_alarm_duration = self.min_alarm_duration;
+        let buzzer_idle = self.buzzer_idle_level();
+        let timestamp_unit = self.timestamp_unit;
+        let clock_fallback = self.clock_fallback;
+        let event_bus = self.event_bus.clone();
+        let on_clear = self.on_clear.clone();
+        let immediate_first = self.immediate_first;
+        let coalesce_gap = self.coalesce_gap;
+        let blackout = self.blackout;
+        let silence = self.silence.clone();
+        let mut shutdown = self.shutdown.as_ref().map(|c| c.register());
+        let buzzer = self.buzzer.clone();
+        // A simulated alarm only logs; buzzer writes are no-ops while no pin is installed
+        if let Some(pin) = buzzer_pin {
+            buzzer.install(pin);
+        }
+        let live = self.live.start_run();
+        live.set((flame_sensor.read() == Level::High) == self.is_high_active());
+
+        // Run monitoring in a separate task
+        let handle = tokio::spawn(async move {
+            // Released in reverse order on exit: the flame pin first, then the
+            // published state, and finally the run so monitoring can restart
+            let _finished = run.clone().drop_guard();
+            let live = live;
+            let flame_sensor = flame_sensor;
+            let publish = |event: SensorEvent| {
+                if let Some(bus) = &event_bus {
+                    bus.publish(event);
+                }
+            };
+            let silenced = || silence.is_silenced() || blackout.is_some_and(|w| w.is_active_now());
+
+            // Initial state: turn off buzzer
+            buzzer.write(buzzer_idle);
+
+            if !immediate_first {
+                let interval = check_interval_clone.load(Ordering::Relaxed);
+                tokio::select! {
+                    _ = sleep(Duration::from_millis(interval)) => {}
+                    _ = run.cancelled() => {}
+                }
+            }
+
+            // Detection state of the previous iteration, for edge events
+            let mut was_detected = false;
+            let mut detected_since = Instant::now();
+            let mut last_seen = Instant::now();
+            let mut alarm_started: Option<Instant> = None;
+
+            // Monitoring loop
+            loop {
+                // Check if monitoring should continue
+                {
+                    if run.is_cancelled()
+                        || shutdown.as_ref().is_some_and(|h| h.is_shutdown())
+                        || cancel.as_ref().is_some_and(|t| t.is_cancelled())
+                    {
+                        buzzer.release(buzzer_idle); // Ensure buzzer is off
+                        break;
+                    }
+                }
+
+                // Detect flame based on configuration
+                flame_input.set_high_active(high_active.load(Ordering::Relaxed));
+                let flame_detected = flame_input.update(flame_sensor.read());
+                live.set(flame_detected);
+
+                // Coalesce flicker: a detection only clears after the gap passes without flame
+                if flame_detected {
+                    last_seen = Instant::now();
+                }
+                let event_detected =
+                    flame_detected || (was_detected && last_seen.elapsed() < coalesce_gap);
+
+                // Publish detection state changes
+                if event_detected != was_detected {
+                    was_detected = event_detected;
+                    let data = FireSensorData {
+                        flame_detected: event_detected,
+                        last_detection_timestamp: if event_detected {
+                            timestamp_unit.now(clock_fallback).ok()
+                        } else {
+                            None
+                        },
+                    };
+                    if let Some(tx) = &edge_tx
+                        && let Err(TrySendError::Full(_)) = tx.try_send(data)
+                    {
+                        log::warn!("Fire event channel full, dropping event");
+                    }
+                    if event_detected {
+                        detected_since = Instant::now();
+                        publish(SensorEvent::FlameDetected(data));
+                        if silenced() {
+                            publish(SensorEvent::AlarmSuppressed(data));
+                        }
+                    } else {
+                        buzzer.write(buzzer_idle); // Silence before running the clear action
+                        publish(SensorEvent::FlameCleared(data));
+                        if let Some(on_clear) = &on_clear {
+                            on_clear(detected_since.elapsed());
+                        }
+                    }
+                }
+
+                if flame_detected {
+                    log::warn!("Flame detected!");
+                }
+
+                // Keep sounding until the minimum alarm time has passed
+                let alarm_held = alarm_started.is_some_and(|t| t.elapsed() < min_alarm_duration);
+
+                // Flame detection
+                if flame_detected || alarm_held {
+                    // Sound the alarm unless blacked out, muted or snoozed
+                    if silenced() {
+                        alarm_started = None;
+                        buzzer.write(buzzer_idle);
+                    } else {
+                        alarm_started.get_or_insert_with(Instant::now);
+                        buzzer.play_tone(alarm_frequency_hz, alarm_duration_ms, buzzer_idle);
+                    }
+                } else {
+                    // No flame - ensure buzzer is off
+                    alarm_started = None;
+                    buzzer.write(buzzer_idle);
+                }
+
+                // Wait for next check, waking early on shutdown or cancellation
+                let interval = Duration::from_millis(check_interval_clone.load(Ordering::Relaxed));
+                let shutdown_signalled = async {
+                    match &mut shutdown {
+                        Some(handle) => handle.signalled().await,
+                        None => std::future::pending().await,
+                    }
+                };
+                let cancelled = async {
+                    match &cancel {
+                        Some(token) => token.cancelled().await,
+                        None => std::future::pending().await,
+                    }
+                };
+                tokio::select! {
+                    _ = sleep(interval) => {}
+                    _ = run.cancelled() => {}
+                    _ = shutdown_signalled => {}
+                    _ = cancelled => {}
+                }
+            }
+        });
+
+        Ok(handle)
+    }
+}
+
+impl Drop for FireSensor {
+    /// Stop any monitoring loop and silence the buzzer right away
+    ///
+    /// Waits for a tone in progress to finish (at most one alarm tone).
+    fn drop(&mut self) {
+        self.monitor.stop();
+        self.buzzer.write(self.buzzer_idle_level());
+    }
 }
 
 #[async_trait]
 impl FireDetector for FireSensor {
     /// Synchronously read fire sensor status
     ///
+    /// While a monitoring loop runs it holds the flame pin, so the reading is
+    /// the loop's debounced detection state from its last check rather than a
+    /// fresh sample; the timestamp is still taken at read time.
+    ///
     /// # Returns
     /// Fire sensor data or error
     ///
@@ -110,6 +1728,8 @@ impl FireDetector for FireSensor {
 
     /// Asynchronously read fire sensor status
     ///
+    /// Served from the monitoring loop's state while it runs, as with `read`.
+    ///
     /// # Returns
     /// Fire sensor data or error
     ///
@@ -129,13 +1749,32 @@ impl FireDetector for FireSensor {
     /// }
     /// ```
     async fn read_async(&self) -> Result<FireSensorData, SensorError> {
+        // No blocking pin access while the monitoring loop holds the pin
+        if self.live.get().is_some() {
+            return self.read_internal();
+        }
+
+        let gpio = self.gpio.clone();
         let flame_pin = self.flame_pin;
-        let high_active = self.high_active;
+        let pull = self.pull;
+        let high_active_setting = self.high_active.clone();
+        let high_active = self.is_high_active();
+        let timestamp_unit = self.timestamp_unit;
+        let clock_fallback = self.clock_fallback;
+        let permit = match &self.read_limiter {
+            Some(limiter) => Some(
+                limiter
+                    .clone()
+                    .try_acquire_owned()
+                    .map_err(|_| SensorError::SensorError("read queue full".into()))?,
+            ),
+            None => None,
+        };
 
         // Execute the read operation in a blocking task
         task::spawn_blocking(move || {
-            let gpio = Gpio::new()?;
-            let flame_sensor = gpio.get(flame_pin)?.into_input();
+            let _permit = permit;
+            let flame_sensor = FlameInput::open(&gpio, flame_pin, pull, &high_active_setting)?;
 
             // Determine flame detection based on configuration
             let flame_detected = if high_active {
@@ -145,12 +1784,7 @@ impl FireDetector for FireSensor {
             };
 
             let timestamp = if flame_detected {
-                Some(
-                    std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .map_err(|e| SensorError::SensorError(format!("Time error: {}", e)))?
-                        .as_secs(),
-                )
+                Some(timestamp_unit.now(clock_fallback)?)
             } else {
                 None
             };
@@ -166,11 +1800,17 @@ impl FireDetector for FireSensor {
 
     /// Start monitoring for fire with the given check interval
     ///
+    /// The flame and buzzer pins are acquired before the loop is spawned, so a
+    /// pin that can't be opened is returned as an error. Only one loop runs per
+    /// sensor, whichever way it was started: starting again before
+    /// `stop_monitoring` fails, and so may a restart right after it while the
+    /// old loop is still releasing the pins.
+    ///
     /// # Arguments
     /// * `check_interval_ms` - Interval in milliseconds between checks
     ///
     /// # Returns
-    /// Ok if monitoring started successfully, Error otherwise
+    /// Handle of the monitoring task if monitoring started successfully, Error otherwise
     ///
     /// # Example
     /// ```no_run
@@ -180,97 +1820,55 @@ impl FireDetector for FireSensor {
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let sensor = FireSensor::new(27, 17, true);
-    ///     sensor.start_monitoring(100).await?;
+    ///     let handle = sensor.start_monitoring(100).await?;
     ///     
     ///     // Do other things while monitoring runs in background
     ///     
-    ///     // Stop monitoring
+    ///     // Stop monitoring and wait until the buzzer is released
     ///     sensor.stop_monitoring();
+    ///     handle.await?;
     ///     Ok(())
     /// }
     /// ```
-    async fn start_monitoring(&self, check_interval_ms: u64) -> Result<(), SensorError> {
-        println!("Starting fire monitoring (async version)");
-        println!(
-            "Sensor configuration: {} level active",
-            if self.high_active { "high" } else { "low" }
-        );
-
-        // Initialize GPIO
-        let gpio = Gpio::new()?;
-        let flame_pin_clone = self.flame_pin;
-        let buzzer_pin_clone = self.buzzer_pin;
-        let is_active_clone = self.is_active.clone();
-        let high_active = self.high_active;
-
-        // Run monitoring in a separate task
-        tokio::spawn(async move {
-            // Initialize GPIO pins
-            let flame_sensor = match gpio.get(flame_pin_clone) {
-                Ok(pin) => pin.into_input(),
-                Err(e) => {
-                    eprintln!("Failed to initialize flame sensor: {}", e);
-                    return;
-                }
-            };
-
-            let mut buzzer = match gpio.get(buzzer_pin_clone) {
-                Ok(pin) => pin.into_output(),
-                Err(e) => {
-                    eprintln!("Failed to initialize buzzer: {}", e);
-                    return;
-                }
-            };
-
-            // Initial state: turn off buzzer
-            buzzer.set_high();
-
-            // Monitoring loop
-            loop {
-                // Check if monitoring should continue
-                {
-                    let is_active = is_active_clone.lock().unwrap();
-                    if !*is_active {
-                        buzzer.set_high(); // Ensure buzzer is off
-                        break;
-                    }
-                }
-
-                // Detect flame based on configuration
-                let flame_detected = if high_active {
-                    flame_sensor.read() == Level::High
-                } else {
-                    flame_sensor.read() == Level::Low
-                };
-
-                // Flame detection
-                if flame_detected {
-                    println!("WARNING: Flame detected!");
-
-                    // Sound the alarm
-                    const ALARM_FREQ: u32 = 1000; // 1kHz
-                    const ALARM_DURATION: u64 = 200; // Duration of each tone (ms)
-
-                    let half_period = 1_000_000 / ALARM_FREQ / 2;
-                    let cycles = ALARM_DURATION * 1000 / (half_period as u64 * 2);
-
-                    for _ in 0..cycles {
-                        buzzer.set_low();
-                        std::thread::sleep(std::time::Duration::from_micros(half_period as u64));
-                        buzzer.set_high();
-                        std::thread::sleep(std::time::Duration::from_micros(half_period as u64));
-                    }
-                } else {
-                    // No flame - ensure buzzer is off
-                    buzzer.set_high();
-                }
-
-                // Wait for next check
-                sleep(Duration::from_millis(check_interval_ms)).await;
-            }
-        });
-
-        Ok(())
+    ///
+    /// Against a `MockGpio` (with the `mock` feature):
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use env_monitor::sensors::mock::MockGpio;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let gpio = MockGpio::new();
+    /// let sensor = FireSensor::new(27, 22, true).with_gpio(gpio.clone());
+    /// let handle = sensor.start_monitoring(10).await.unwrap();
+    ///
+    /// // A second loop is refused and leaves the running one untouched
+    /// assert!(sensor.start_monitoring(10).await.is_err());
+    /// assert!(sensor.start_monitoring_interrupt().await.is_err());
+    /// assert!(sensor.read().is_ok());
+    ///
+    /// sensor.stop_monitoring();
+    /// handle.await.unwrap();
+    /// let handle = sensor.start_monitoring(10).await.unwrap();
+    /// sensor.stop_monitoring();
+    /// handle.await.unwrap();
+    ///
+    /// // A pin held elsewhere fails the start instead of the spawned task
+    /// let other = FireSensor::new(27, 23, true).with_gpio(gpio.clone());
+    /// let handle = other.start_monitoring(10).await.unwrap();
+    /// assert!(sensor.start_monitoring(10).await.is_err());
+    /// other.stop_monitoring();
+    /// handle.await.unwrap();
+    /// assert!(sensor.start_monitoring(10).await.is_ok());
+    /// # });
+    /// # }
+    /// ```
+    async fn start_monitoring(
+        &self,
+        check_interval_ms: u64,
+    ) -> Result<JoinHandle<()>, SensorError> {
+        self.spawn_monitoring(check_interval_ms, None, None)
     }
 
     /// Stop monitoring for fire
@@ -293,7 +1891,6 @@ impl FireDetector for FireSensor {
     /// }
     /// ```
     fn stop_monitoring(&self) {
-        let mut is_active = self.is_active.lock().unwrap();
-        *is_active = false;
+        self.monitor.stop();
     }
 }
@@ -0,0 +1,303 @@
+//! Flame sensor array that votes before raising the alarm
+
+use async_trait::async_trait;
+use rppal::gpio::Level;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::task::{self, JoinHandle};
+use tokio::time::Duration;
+
+use crate::alarm::AlarmBuzzer;
+use crate::clock::{ClockFallback, TimestampUnit};
+use crate::error::SensorError;
+use crate::sensors::fire::{FireSensorData, Pull};
+use crate::sensors::gpio::{GpioHandle, InputLine};
+use crate::sensors::traits::FireDetector;
+
+/// Several flame sensors watching the same area, sharing one buzzer
+///
+/// A single flame sensor false-triggers on sunlight and IR reflections. The
+/// array only reports a flame when at least `quorum` of its sensors agree, and
+/// its monitoring loop applies the same vote before sounding the buzzer. It
+/// implements [`FireDetector`], so it can replace a single `FireSensor`.
+///
+/// # Example
+/// ```no_run
+/// use env_monitor::sensors::FireDetector;
+/// use env_monitor::sensors::fire_array::FireSensorArray;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     // Two of three sensors must see the flame
+///     let array = FireSensorArray::new(vec![27, 23, 24], 22, true, 2);
+///     let handle = array.start_monitoring(100).await?;
+///
+///     // Do other things...
+///
+///     array.stop_monitoring();
+///     handle.await?;
+///     Ok(())
+/// }
+/// ```
+pub struct FireSensorArray {
+    /// GPIO pin numbers connected to the flame sensors
+    flame_pins: Vec<u8>,
+    /// GPIO pin number connected to the buzzer
+    buzzer_pin: u8,
+    /// Sensor logic shared by every sensor (true = high level active, false = low level active)
+    high_active: bool,
+    /// Number of sensors that must detect a flame for the array to report one
+    quorum: usize,
+    /// Buzzer logic (true = sounds on high level, false = sounds on low level)
+    buzzer_active_high: bool,
+    /// Alarm tone frequency in Hz
+    alarm_frequency_hz: u32,
+    /// Duration of each alarm tone in milliseconds
+    alarm_duration_ms: u64,
+    /// Monitoring active state, cleared by `stop_monitoring`
+    is_active: Arc<AtomicBool>,
+    /// GPIO controller the pins are acquired from
+    gpio: GpioHandle,
+}
+
+impl FireSensorArray {
+    /// Create a new flame sensor array
+    ///
+    /// The quorum is clamped to between 1 and the number of sensors.
+    ///
+    /// # Arguments
+    /// * `flame_pins` - GPIO pin numbers connected to the flame sensors
+    /// * `buzzer_pin` - GPIO pin number connected to the buzzer
+    /// * `high_active` - Sensor logic (true if high level indicates flame detection)
+    /// * `quorum` - Number of sensors that must agree before a flame is reported
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire_array::FireSensorArray;
+    ///
+    /// let array = FireSensorArray::new(vec![27, 23, 24], 22, true, 2);
+    /// assert_eq!(array.quorum(), 2);
+    ///
+    /// // More than the number of sensors means all of them
+    /// let array = FireSensorArray::new(vec![27, 23], 22, true, 5);
+    /// assert_eq!(array.quorum(), 2);
+    /// ```
+    pub fn new(flame_pins: Vec<u8>, buzzer_pin: u8, high_active: bool, quorum: usize) -> Self {
+        let quorum = quorum.clamp(1, flame_pins.len().max(1));
+        FireSensorArray {
+            flame_pins,
+            buzzer_pin,
+            high_active,
+            quorum,
+            buzzer_active_high: false,
+            alarm_frequency_hz: 1000, // 1kHz
+            alarm_duration_ms: 200,
+            is_active: Arc::new(AtomicBool::new(false)),
+            gpio: GpioHandle::default(),
+        }
+    }
+
+    /// Acquire the pins from the given GPIO controller
+    ///
+    /// Accepts an opened `rppal::gpio::Gpio` (or `Arc<Gpio>`) to share one
+    /// controller between sensors, or a `MockGpio` with the `mock` feature. By
+    /// default the array opens the controller itself on first use.
+    pub fn with_gpio(mut self, gpio: impl Into<GpioHandle>) -> Self {
+        self.gpio = gpio.into();
+        self
+    }
+
+    /// Set the buzzer logic (true if the buzzer sounds on high level)
+    ///
+    /// Defaults to false, matching the common active-low buzzer modules.
+    pub fn with_buzzer_active_high(mut self, active_high: bool) -> Self {
+        self.buzzer_active_high = active_high;
+        self
+    }
+
+    /// Set the alarm tone sounded on the buzzer (1kHz for 200ms by default)
+    ///
+    /// A frequency of 0 keeps the buzzer silent; frequencies above
+    /// [`MAX_TONE_FREQUENCY_HZ`](crate::alarm::MAX_TONE_FREQUENCY_HZ) (500kHz)
+    /// are clamped to it.
+    pub fn with_alarm_tone(mut self, frequency_hz: u32, duration_ms: u64) -> Self {
+        self.alarm_frequency_hz = frequency_hz;
+        self.alarm_duration_ms = duration_ms;
+        self
+    }
+
+    /// GPIO pin numbers of the flame sensors
+    pub fn flame_pins(&self) -> &[u8] {
+        &self.flame_pins
+    }
+
+    /// Number of sensors that must agree before a flame is reported
+    pub fn quorum(&self) -> usize {
+        self.quorum
+    }
+}
+
+/// Whether at least `quorum` of the sensors' detections agree on a flame
+///
+/// The quorum is clamped to between 1 and the number of detections, as in
+/// [`FireSensorArray::new`]. An empty slice never reports a flame.
+///
+/// # Example
+/// ```
+/// use env_monitor::sensors::fire_array::quorum_vote;
+///
+/// // Quorum met and not met
+/// assert!(quorum_vote(&[true, false, true], 2));
+/// assert!(!quorum_vote(&[true, false, false], 2));
+///
+/// // A quorum above the sensor count needs every sensor
+/// assert!(quorum_vote(&[true, true], 5));
+/// assert!(!quorum_vote(&[true, false], 5));
+///
+/// // No sensors, no flame
+/// assert!(!quorum_vote(&[], 1));
+/// ```
+pub fn quorum_vote(detections: &[bool], quorum: usize) -> bool {
+    if detections.is_empty() {
+        return false;
+    }
+    let quorum = quorum.clamp(1, detections.len());
+    detections.iter().filter(|&&detected| detected).count() >= quorum
+}
+
+// Read every sensor and vote on whether the quorum detects a flame
+fn vote(sensors: &[InputLine], high_active: bool, quorum: usize) -> bool {
+    let active_level = if high_active { Level::High } else { Level::Low };
+    let detections: Vec<bool> = sensors
+        .iter()
+        .map(|pin| pin.read() == active_level)
+        .collect();
+    quorum_vote(&detections, quorum)
+}
+
+// Acquire every flame sensor pin as an input
+fn acquire_inputs(gpio: &GpioHandle, flame_pins: &[u8]) -> Result<Vec<InputLine>, SensorError> {
+    flame_pins
+        .iter()
+        .map(|&pin| gpio.input(pin, Pull::None))
+        .collect()
+}
+
+// Take one vote and build the reading from it
+fn read_array(
+    gpio: &GpioHandle,
+    flame_pins: &[u8],
+    high_active: bool,
+    quorum: usize,
+) -> Result<FireSensorData, SensorError> {
+    let sensors = acquire_inputs(gpio, flame_pins)?;
+    let flame_detected = vote(&sensors, high_active, quorum);
+
+    let timestamp = if flame_detected {
+        Some(TimestampUnit::default().now(ClockFallback::default())?)
+    } else {
+        None
+    };
+
+    Ok(FireSensorData {
+        flame_detected,
+        last_detection_timestamp: timestamp,
+    })
+}
+
+#[async_trait]
+impl FireDetector for FireSensorArray {
+    /// Synchronously read the array, reporting a flame only on quorum
+    fn read(&self) -> Result<FireSensorData, SensorError> {
+        read_array(&self.gpio, &self.flame_pins, self.high_active, self.quorum)
+    }
+
+    /// Asynchronously read the array, reporting a flame only on quorum
+    async fn read_async(&self) -> Result<FireSensorData, SensorError> {
+        let gpio = self.gpio.clone();
+        let flame_pins = self.flame_pins.clone();
+        let high_active = self.high_active;
+        let quorum = self.quorum;
+
+        // Execute the read operation in a blocking task
+        task::spawn_blocking(move || read_array(&gpio, &flame_pins, high_active, quorum))
+            .await
+            .map_err(|e| SensorError::SensorError(format!("Task join error: {}", e)))?
+    }
+
+    /// Start monitoring, sounding the buzzer while the quorum detects a flame
+    ///
+    /// Against a `MockGpio` (with the `mock` feature), one sensor alone
+    /// doesn't sound the alarm but two of three do:
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::fire_array::FireSensorArray;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use rppal::gpio::Level;
+    /// use std::time::Duration;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let gpio = MockGpio::new();
+    /// let array = FireSensorArray::new(vec![27, 23, 24], 22, true, 2)
+    ///     .with_alarm_tone(1000, 10)
+    ///     .with_gpio(gpio.clone());
+    /// let handle = array.start_monitoring(5).await.unwrap();
+    ///
+    /// let sounded = || gpio.writes(22).iter().any(|&(_, level)| level == Level::Low);
+    ///
+    /// gpio.set_level(27, Level::High);
+    /// tokio::time::sleep(Duration::from_millis(30)).await;
+    /// assert!(!sounded());
+    ///
+    /// gpio.set_level(23, Level::High);
+    /// tokio::time::sleep(Duration::from_millis(50)).await;
+    /// assert!(sounded());
+    ///
+    /// array.stop_monitoring();
+    /// handle.await.unwrap();
+    /// assert_eq!(gpio.output_level(22), Some(Level::High));
+    /// # });
+    /// # }
+    /// ```
+    async fn start_monitoring(
+        &self,
+        check_interval_ms: u64,
+    ) -> Result<JoinHandle<()>, SensorError> {
+        log::info!(
+            "Starting fire monitoring on {} sensors with quorum {}",
+            self.flame_pins.len(),
+            self.quorum
+        );
+
+        let sensors = acquire_inputs(&self.gpio, &self.flame_pins)?;
+        let buzzer = AlarmBuzzer::new(
+            self.gpio.output(self.buzzer_pin)?,
+            self.buzzer_active_high,
+            self.alarm_frequency_hz,
+            self.alarm_duration_ms,
+        );
+        self.is_active.store(true, Ordering::Relaxed);
+
+        let high_active = self.high_active;
+        let quorum = self.quorum;
+
+        Ok(buzzer.spawn_loop(
+            Duration::from_millis(check_interval_ms),
+            self.is_active.clone(),
+            move || vote(&sensors, high_active, quorum),
+            move |flame_detected| {
+                if flame_detected {
+                    log::warn!("Flame detected by at least {} sensors!", quorum);
+                } else {
+                    log::info!("Flame cleared");
+                }
+            },
+        ))
+    }
+
+    /// Stop monitoring
+    fn stop_monitoring(&self) {
+        self.is_active.store(false, Ordering::Relaxed);
+    }
+}
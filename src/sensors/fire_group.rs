@@ -0,0 +1,203 @@
+//! Multi-zone fire monitoring with one buzzer per flame sensor
+
+use crate::error::SensorError;
+use crate::sensors::fire::FireSensor;
+use crate::sensors::gpio::GpioHandle;
+use crate::sensors::traits::FireDetector;
+use tokio::task::JoinHandle;
+
+/// Group of independent fire sensor/buzzer pairs sharing one configuration
+///
+/// Each zone pairs a flame sensor with its own buzzer and alarm tone. Starting
+/// the group spawns one monitoring task per zone, so resource usage grows
+/// linearly with the number of zones (one task and two GPIO pins each).
+///
+/// Shared settings apply to every zone, whether it was added before or after
+/// they were set.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "mock")] {
+/// use env_monitor::sensors::fire_group::FireSensorGroup;
+/// use env_monitor::sensors::mock::MockGpio;
+/// use rppal::gpio::Level;
+/// use std::time::Duration;
+///
+/// // Hold a zone's flame for a while, with every zone monitored
+/// async fn flame(group: &FireSensorGroup, gpio: &MockGpio, flame_pin: u8) {
+///     gpio.set_level(flame_pin, Level::High);
+///     let handles = group.start_monitoring(10).await.unwrap();
+///     tokio::time::sleep(Duration::from_millis(150)).await;
+///     group.stop_monitoring();
+///     for handle in handles {
+///         handle.await.unwrap();
+///     }
+///     gpio.set_level(flame_pin, Level::Low);
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let gpio = MockGpio::new();
+/// let group = FireSensorGroup::new(true)
+///     .add_zone(27, 22, 1000)
+///     .add_zone(23, 24, 2000)
+///     .with_gpio(gpio.clone())
+///     .with_alarm_duration(20);
+///
+/// let alarm_writes = |zone: usize, buzzer_pin| {
+///     let idle = group.zones()[zone].buzzer_idle_level();
+///     gpio.writes(buzzer_pin).iter().filter(|&&(_, level)| level != idle).count()
+/// };
+///
+/// // A flame in the first zone only sounds the first zone's buzzer
+/// flame(&group, &gpio, 27).await;
+/// let first = alarm_writes(0, 22);
+/// assert!(first > 0);
+/// assert_eq!(alarm_writes(1, 24), 0);
+///
+/// // And the other way round, with the second zone's higher tone
+/// flame(&group, &gpio, 23).await;
+/// assert!(alarm_writes(1, 24) > 0);
+/// assert_eq!(alarm_writes(0, 22), first);
+/// # });
+/// # }
+/// ```
+pub struct FireSensorGroup {
+    /// Zone sensors, each driving its own buzzer, built from `specs`
+    zones: Vec<FireSensor>,
+    /// Flame pin, buzzer pin and alarm frequency of each zone
+    specs: Vec<(u8, u8, u32)>,
+    /// Shared sensor logic configuration (true = high level active, false = low level active)
+    high_active: bool,
+    /// Shared alarm tone duration in milliseconds
+    alarm_duration_ms: u64,
+    /// GPIO controller shared by every zone
+    gpio: GpioHandle,
+}
+
+impl FireSensorGroup {
+    /// Create an empty fire sensor group
+    ///
+    /// # Arguments
+    /// * `high_active` - Sensor logic shared by every zone
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire_group::FireSensorGroup;
+    ///
+    /// let group = FireSensorGroup::new(true)
+    ///     .add_zone(27, 22, 1000)
+    ///     .add_zone(23, 24, 2000);
+    /// assert_eq!(group.zones().len(), 2);
+    /// ```
+    pub fn new(high_active: bool) -> Self {
+        FireSensorGroup {
+            zones: Vec::new(),
+            specs: Vec::new(),
+            high_active,
+            alarm_duration_ms: 200,
+            gpio: GpioHandle::default(),
+        }
+    }
+
+    /// Acquire the pins of every zone from the given GPIO controller
+    pub fn with_gpio(mut self, gpio: impl Into<GpioHandle>) -> Self {
+        self.gpio = gpio.into();
+        self.rebuild_zones();
+        self
+    }
+
+    /// Set the alarm tone duration shared by every zone
+    pub fn with_alarm_duration(mut self, duration_ms: u64) -> Self {
+        self.alarm_duration_ms = duration_ms;
+        self.rebuild_zones();
+        self
+    }
+
+    /// Add a zone pairing a flame sensor with its own buzzer and tone
+    ///
+    /// As with [`FireSensor::with_alarm_tone`], a frequency of 0 keeps the
+    /// zone's buzzer silent and frequencies above 500kHz are clamped.
+    ///
+    /// # Arguments
+    /// * `flame_pin` - GPIO pin number connected to the zone's flame sensor
+    /// * `buzzer_pin` - GPIO pin number connected to the zone's buzzer
+    /// * `alarm_frequency_hz` - Alarm tone frequency for this zone
+    pub fn add_zone(mut self, flame_pin: u8, buzzer_pin: u8, alarm_frequency_hz: u32) -> Self {
+        let spec = (flame_pin, buzzer_pin, alarm_frequency_hz);
+        self.zones.push(self.build_zone(spec));
+        self.specs.push(spec);
+        self
+    }
+
+    // Build a zone's sensor with the current shared settings
+    fn build_zone(&self, (flame_pin, buzzer_pin, alarm_frequency_hz): (u8, u8, u32)) -> FireSensor {
+        FireSensor::new(flame_pin, buzzer_pin, self.high_active)
+            .with_alarm_tone(alarm_frequency_hz, self.alarm_duration_ms)
+            .with_gpio(self.gpio.clone())
+    }
+
+    // Rebuild every zone after a shared setting changed
+    fn rebuild_zones(&mut self) {
+        self.zones = self
+            .specs
+            .iter()
+            .map(|&spec| self.build_zone(spec))
+            .collect();
+    }
+
+    /// Sensors making up the group, in the order they were added
+    pub fn zones(&self) -> &[FireSensor] {
+        &self.zones
+    }
+
+    /// Start monitoring every zone with the given check interval
+    ///
+    /// Returns the monitoring task handle of each zone, in zone order. If a
+    /// zone fails to start, zones already started are stopped again.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::fire_group::FireSensorGroup;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let group = FireSensorGroup::new(true)
+    ///         .add_zone(27, 22, 1000)
+    ///         .add_zone(23, 24, 2000);
+    ///     let handles = group.start_monitoring(100).await?;
+    ///
+    ///     // Do other things...
+    ///
+    ///     group.stop_monitoring();
+    ///     for handle in handles {
+    ///         handle.await?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn start_monitoring(
+        &self,
+        check_interval_ms: u64,
+    ) -> Result<Vec<JoinHandle<()>>, SensorError> {
+        let mut handles = Vec::with_capacity(self.zones.len());
+
+        for (started, zone) in self.zones.iter().enumerate() {
+            match zone.start_monitoring(check_interval_ms).await {
+                Ok(handle) => handles.push(handle),
+                Err(e) => {
+                    self.zones[..started]
+                        .iter()
+                        .for_each(|z| z.stop_monitoring());
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(handles)
+    }
+
+    /// Stop monitoring every zone
+    pub fn stop_monitoring(&self) {
+        self.zones.iter().for_each(|zone| zone.stop_monitoring());
+    }
+}
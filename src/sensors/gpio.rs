@@ -0,0 +1,415 @@
+//! GPIO controller handle the sensors acquire their pins from
+
+#[cfg(feature = "gpiod")]
+use gpio_cdev::{Chip, Line, LineHandle, LineRequestFlags};
+use rppal::gpio::{self, Gpio, InputPin, IoPin, Level, Mode, OutputPin, Trigger};
+use std::io;
+#[cfg(feature = "gpiod")]
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use crate::error::SensorError;
+use crate::sensors::fire::Pull;
+#[cfg(feature = "mock")]
+use crate::sensors::mock::{MockGpio, MockLine};
+
+/// Handle to the GPIO controller a sensor takes its pins from
+///
+/// Every GPIO sensor holds one. By default the handle opens the Raspberry Pi's
+/// controller the first time a pin is needed and keeps it open for the life of
+/// the sensor, so sensors can still be constructed off-device and repeated
+/// reads don't re-open the character device. Pass an opened [`Gpio`] to a
+/// sensor's `with_gpio` to share one controller between sensors, or a
+/// [`MockGpio`](crate::sensors::mock::MockGpio) (with the `mock` feature) to
+/// run the sensors without hardware. Where rppal can't open the controller,
+/// [`GpioHandle::gpiod`] (with the `gpiod` feature) goes through the Linux GPIO
+/// character device instead. Clones share the same controller.
+///
+/// # Example
+/// ```no_run
+/// use env_monitor::sensors::dht11::Dht11Sensor;
+/// use env_monitor::sensors::fire::FireSensor;
+/// use rppal::gpio::Gpio;
+/// use std::sync::Arc;
+///
+/// let gpio = Arc::new(Gpio::new().unwrap());
+/// let dht11 = Dht11Sensor::new(17).with_gpio(gpio.clone());
+/// let fire = FireSensor::new(27, 22, true).with_gpio(gpio);
+/// ```
+#[derive(Clone, Default)]
+pub struct GpioHandle(Backend);
+
+#[derive(Clone)]
+enum Backend {
+    /// Raspberry Pi controller opened by the sensor, `None` until first used
+    Default(Arc<Mutex<Option<Arc<Gpio>>>>),
+    /// Raspberry Pi controller passed in by the caller
+    Rppal(Arc<Gpio>),
+    /// Simulated controller for tests
+    #[cfg(feature = "mock")]
+    Mock(MockGpio),
+    /// Linux GPIO character device, opened on first use
+    #[cfg(feature = "gpiod")]
+    Gpiod(Arc<GpiodChip>),
+}
+
+// GPIO character device, shared by the handle's clones
+#[cfg(feature = "gpiod")]
+struct GpiodChip {
+    /// Path of the character device
+    path: PathBuf,
+    /// Opened device, `None` until first used
+    chip: Mutex<Option<Chip>>,
+}
+
+/// Consumer label the gpiod backend requests its lines under
+#[cfg(feature = "gpiod")]
+const GPIOD_CONSUMER: &str = "env_monitor";
+
+impl Default for Backend {
+    fn default() -> Self {
+        Backend::Default(Arc::default())
+    }
+}
+
+impl From<Arc<Gpio>> for GpioHandle {
+    fn from(gpio: Arc<Gpio>) -> Self {
+        GpioHandle(Backend::Rppal(gpio))
+    }
+}
+
+impl From<Gpio> for GpioHandle {
+    fn from(gpio: Gpio) -> Self {
+        Arc::new(gpio).into()
+    }
+}
+
+#[cfg(feature = "mock")]
+impl From<MockGpio> for GpioHandle {
+    fn from(gpio: MockGpio) -> Self {
+        GpioHandle(Backend::Mock(gpio))
+    }
+}
+
+#[cfg(feature = "gpiod")]
+impl GpioHandle {
+    /// Take pins from a Linux GPIO character device instead of through rppal
+    ///
+    /// For distributions where rppal's `/dev/gpiomem` access is unavailable
+    /// but the character device (the interface libgpiod uses) works. Pins are
+    /// line offsets on the chip, which on a Raspberry Pi's `/dev/gpiochip0`
+    /// are the BCM numbers. The device is opened when a pin is first needed.
+    ///
+    /// Every read and write is an ioctl, a few microseconds each against a
+    /// memory-mapped register access with rppal, and switching the DHT data
+    /// line to input re-requests the line. The DHT11's 27µs and 70µs bit pulses
+    /// still sit well clear of the 40µs threshold on a Pi 3 or newer, but a
+    /// slow or loaded board fails more reads with checksum errors or timeouts.
+    /// The character device interface has no pull resistor setting, so
+    /// inputs need `Pull::None` and an external resistor, and it has no
+    /// interrupt-driven monitoring; polled monitoring works as usual.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "gpiod")] {
+    /// use env_monitor::error::SensorError;
+    /// use env_monitor::sensors::FireDetector;
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use env_monitor::sensors::gpio::GpioHandle;
+    ///
+    /// // The pins come from the given device rather than rppal's controller
+    /// let sensor = FireSensor::new(27, 22, true).with_gpio(GpioHandle::gpiod("/dev/gpiochip-missing"));
+    /// match sensor.read() {
+    ///     Err(SensorError::InitError(msg)) => assert!(msg.contains("/dev/gpiochip-missing")),
+    ///     other => panic!("expected the gpiod device to fail to open, got {:?}", other),
+    /// }
+    /// # }
+    /// ```
+    pub fn gpiod(path: impl Into<PathBuf>) -> Self {
+        GpioHandle(Backend::Gpiod(Arc::new(GpiodChip {
+            path: path.into(),
+            chip: Mutex::new(None),
+        })))
+    }
+}
+
+#[cfg(feature = "gpiod")]
+impl GpiodChip {
+    // Request a line of the device, opening it on first use
+    fn request(&self, pin: u8, flags: LineRequestFlags) -> Result<(Line, LineHandle), SensorError> {
+        let fail = |e: gpio_cdev::Error| {
+            SensorError::InitError(format!("gpiod {} line {}: {}", self.path.display(), pin, e))
+        };
+
+        let mut chip = self.chip.lock().unwrap();
+        let chip = match chip.as_mut() {
+            Some(chip) => chip,
+            None => chip.insert(Chip::new(&self.path).map_err(fail)?),
+        };
+        let line = chip.get_line(pin.into()).map_err(fail)?;
+        let handle = line.request(flags, 0, GPIOD_CONSUMER).map_err(fail)?;
+        Ok((line, handle))
+    }
+}
+
+impl GpioHandle {
+    // Whether the `simulate` feature stands in for this controller
+    //
+    // Only the default controller is simulated; one passed to `with_gpio` is
+    // always used as given.
+    pub(crate) fn is_simulated(&self) -> bool {
+        cfg!(feature = "simulate") && matches!(self.0, Backend::Default(_))
+    }
+
+    // Hardware controller to take a pin from, opening the default one on first use
+    fn rppal(&self) -> Result<Arc<Gpio>, SensorError> {
+        let slot = match &self.0 {
+            Backend::Default(slot) => slot,
+            Backend::Rppal(gpio) => return Ok(gpio.clone()),
+            #[cfg(feature = "mock")]
+            Backend::Mock(_) => {
+                return Err(SensorError::InitError(
+                    "Mock GPIO has no hardware controller".to_string(),
+                ));
+            }
+            #[cfg(feature = "gpiod")]
+            Backend::Gpiod(_) => {
+                return Err(SensorError::InitError(
+                    "gpiod GPIO has no rppal controller".to_string(),
+                ));
+            }
+        };
+
+        let mut slot = slot.lock().unwrap();
+        if let Some(gpio) = slot.as_ref() {
+            return Ok(gpio.clone());
+        }
+
+        let opened = Arc::new(Gpio::new().map_err(gpio_unavailable)?);
+        *slot = Some(opened.clone());
+        Ok(opened)
+    }
+
+    // Acquire a pin as an input with the given pull resistor
+    pub(crate) fn input(&self, pin: u8, pull: Pull) -> Result<InputLine, SensorError> {
+        match &self.0 {
+            #[cfg(feature = "mock")]
+            Backend::Mock(gpio) => Ok(InputLine::Mock(gpio.claim(pin, pull)?)),
+            #[cfg(feature = "gpiod")]
+            Backend::Gpiod(_) if pull != Pull::None => Err(SensorError::InitError(format!(
+                "gpiod pin {}: pull resistors are not supported, use Pull::None with an external resistor",
+                pin
+            ))),
+            #[cfg(feature = "gpiod")]
+            Backend::Gpiod(chip) => Ok(InputLine::Gpiod(
+                chip.request(pin, LineRequestFlags::INPUT)?.1,
+            )),
+            _ => {
+                let pin = self.rppal()?.get(pin)?;
+                Ok(InputLine::Pin(match pull {
+                    Pull::None => pin.into_input(),
+                    Pull::Up => pin.into_input_pullup(),
+                    Pull::Down => pin.into_input_pulldown(),
+                }))
+            }
+        }
+    }
+
+    // Acquire a pin as an output
+    pub(crate) fn output(&self, pin: u8) -> Result<OutputLine, SensorError> {
+        match &self.0 {
+            #[cfg(feature = "mock")]
+            Backend::Mock(gpio) => Ok(OutputLine::Mock(gpio.claim(pin, Pull::None)?)),
+            #[cfg(feature = "gpiod")]
+            Backend::Gpiod(chip) => Ok(OutputLine::Gpiod(
+                chip.request(pin, LineRequestFlags::OUTPUT)?.1,
+            )),
+            _ => Ok(OutputLine::Pin(self.rppal()?.get(pin)?.into_output())),
+        }
+    }
+
+    // Acquire a pin for single-wire signalling, starting as an output
+    pub(crate) fn io(&self, pin: u8) -> Result<IoLine, SensorError> {
+        match &self.0 {
+            #[cfg(feature = "mock")]
+            Backend::Mock(gpio) => Ok(IoLine::Mock(gpio.claim(pin, Pull::None)?)),
+            #[cfg(feature = "gpiod")]
+            Backend::Gpiod(chip) => {
+                let (line, handle) = chip.request(pin, LineRequestFlags::OUTPUT)?;
+                Ok(IoLine::Gpiod(line, Some(handle)))
+            }
+            _ => Ok(IoLine::Pin(self.rppal()?.get(pin)?.into_io(Mode::Output))),
+        }
+    }
+}
+
+/// Pin acquired as an input
+pub(crate) enum InputLine {
+    Pin(InputPin),
+    #[cfg(feature = "mock")]
+    Mock(MockLine),
+    #[cfg(feature = "gpiod")]
+    Gpiod(LineHandle),
+}
+
+impl InputLine {
+    pub(crate) fn read(&self) -> Level {
+        match self {
+            InputLine::Pin(pin) => pin.read(),
+            #[cfg(feature = "mock")]
+            InputLine::Mock(line) => line.read(),
+            #[cfg(feature = "gpiod")]
+            InputLine::Gpiod(handle) => gpiod_read(handle),
+        }
+    }
+
+    // Call `on_edge` with the new level on every rising and falling edge
+    pub(crate) fn watch_edges<F>(&mut self, mut on_edge: F) -> Result<(), SensorError>
+    where
+        F: FnMut(Level) + Send + 'static,
+    {
+        match self {
+            InputLine::Pin(pin) => {
+                Ok(pin.set_async_interrupt(Trigger::Both, None, move |event| {
+                    on_edge(if event.trigger == Trigger::RisingEdge {
+                        Level::High
+                    } else {
+                        Level::Low
+                    })
+                })?)
+            }
+            #[cfg(feature = "mock")]
+            InputLine::Mock(line) => {
+                line.watch_edges(Box::new(on_edge));
+                Ok(())
+            }
+            #[cfg(feature = "gpiod")]
+            InputLine::Gpiod(_) => Err(SensorError::SensorError(
+                "Interrupt-driven monitoring is not supported by the gpiod backend".into(),
+            )),
+        }
+    }
+}
+
+/// Pin acquired as an output
+pub(crate) enum OutputLine {
+    Pin(OutputPin),
+    #[cfg(feature = "mock")]
+    Mock(MockLine),
+    #[cfg(feature = "gpiod")]
+    Gpiod(LineHandle),
+}
+
+impl OutputLine {
+    pub(crate) fn write(&mut self, level: Level) {
+        match self {
+            OutputLine::Pin(pin) => pin.write(level),
+            #[cfg(feature = "mock")]
+            OutputLine::Mock(line) => line.write(level),
+            #[cfg(feature = "gpiod")]
+            OutputLine::Gpiod(handle) => gpiod_write(handle, level),
+        }
+    }
+
+    // Level the output is currently driven to, read back from the pin
+    pub(crate) fn level(&self) -> Level {
+        match self {
+            OutputLine::Pin(pin) => Level::from(pin.is_set_high()),
+            #[cfg(feature = "mock")]
+            OutputLine::Mock(line) => line.output_level(),
+            #[cfg(feature = "gpiod")]
+            OutputLine::Gpiod(handle) => gpiod_read(handle),
+        }
+    }
+}
+
+/// Pin acquired for single-wire signalling
+pub(crate) enum IoLine {
+    Pin(IoPin),
+    #[cfg(feature = "mock")]
+    Mock(MockLine),
+    /// Line and its current request, `None` only if switching direction failed
+    #[cfg(feature = "gpiod")]
+    Gpiod(Line, Option<LineHandle>),
+}
+
+impl IoLine {
+    pub(crate) fn read(&self) -> Level {
+        match self {
+            IoLine::Pin(pin) => pin.read(),
+            #[cfg(feature = "mock")]
+            IoLine::Mock(line) => line.read(),
+            #[cfg(feature = "gpiod")]
+            IoLine::Gpiod(_, handle) => handle.as_ref().map_or(Level::Low, gpiod_read),
+        }
+    }
+
+    pub(crate) fn write(&mut self, level: Level) {
+        match self {
+            IoLine::Pin(pin) => pin.write(level),
+            #[cfg(feature = "mock")]
+            IoLine::Mock(line) => line.write(level),
+            #[cfg(feature = "gpiod")]
+            IoLine::Gpiod(_, handle) => {
+                if let Some(handle) = handle {
+                    gpiod_write(handle, level);
+                }
+            }
+        }
+    }
+
+    // Release the line to the sensor by switching to input mode
+    pub(crate) fn set_input(&mut self) {
+        match self {
+            IoLine::Pin(pin) => pin.set_mode(Mode::Input),
+            #[cfg(feature = "mock")]
+            IoLine::Mock(line) => line.start_response(),
+            #[cfg(feature = "gpiod")]
+            IoLine::Gpiod(line, handle) => {
+                // The direction is fixed per request, so release the line and request it again
+                handle.take();
+                *handle = line
+                    .request(LineRequestFlags::INPUT, 0, GPIOD_CONSUMER)
+                    .inspect_err(|e| {
+                        log::warn!("gpiod line {} input request failed: {}", line.offset(), e)
+                    })
+                    .ok();
+            }
+        }
+    }
+}
+
+// Read a gpiod line, treating a failed ioctl as low
+#[cfg(feature = "gpiod")]
+fn gpiod_read(handle: &LineHandle) -> Level {
+    match handle.get_value() {
+        Ok(value) => Level::from(value != 0),
+        Err(e) => {
+            log::warn!("gpiod line {} read failed: {}", handle.line().offset(), e);
+            Level::Low
+        }
+    }
+}
+
+// Write a gpiod line, logging a failed ioctl
+#[cfg(feature = "gpiod")]
+fn gpiod_write(handle: &LineHandle, level: Level) {
+    if let Err(e) = handle.set_value(u8::from(level == Level::High)) {
+        log::warn!("gpiod line {} write failed: {}", handle.line().offset(), e);
+    }
+}
+
+// Explain the failures new users hit when opening GPIO off a Pi or without access
+fn gpio_unavailable(err: gpio::Error) -> SensorError {
+    let reason = match &err {
+        gpio::Error::UnknownModel => "not running on a supported Raspberry Pi",
+        gpio::Error::PermissionDenied(_) => {
+            "missing permissions, add the user to the gpio group or run as root"
+        }
+        gpio::Error::Io(e) if e.kind() == io::ErrorKind::NotFound => {
+            "no GPIO device found, not running on a supported Raspberry Pi"
+        }
+        _ => return SensorError::GpioError(err),
+    };
+    SensorError::InitError(format!("GPIO unavailable: {} ({})", reason, err))
+}
@@ -0,0 +1,563 @@
+//! Scripted sensors for testing and demos without GPIO hardware
+//!
+//! Available with the `mock` feature. The mocks implement the same traits as
+//! the hardware sensors, so code written against [`TemperatureSensor`] or
+//! [`FireDetector`] can be exercised in CI or on a development machine.
+//! [`MockGpio`] goes one level lower and runs the hardware sensors themselves
+//! against simulated pins.
+
+use async_trait::async_trait;
+use rppal::gpio::{self, Level};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, sleep};
+
+use crate::clock::{ClockFallback, TimestampUnit};
+use crate::error::SensorError;
+use crate::events::{EventBus, SensorEvent};
+use crate::sensors::dht11::Dht11Data;
+use crate::sensors::fire::{FireSensorData, Pull};
+use crate::sensors::monitoring::MonitorState;
+use crate::sensors::traits::{FireDetector, TemperatureSensor};
+
+/// Temperature sensor returning scripted readings
+///
+/// Readings are returned in order, starting over after the last one. Clones
+/// share the position in the script. Monitoring reads immediately on start.
+///
+/// # Example
+/// ```
+/// use env_monitor::Dht11Data;
+/// use env_monitor::sensors::TemperatureSensor;
+/// use env_monitor::sensors::mock::MockTemperatureSensor;
+///
+/// let sensor = MockTemperatureSensor::new([
+///     Dht11Data { temperature: 21.0, humidity: 40.0, timestamp: 0 },
+///     Dht11Data { temperature: 22.0, humidity: 41.0, timestamp: 0 },
+/// ]);
+/// assert_eq!(sensor.read().unwrap().temperature, 21.0);
+/// assert_eq!(sensor.read().unwrap().temperature, 22.0);
+/// assert_eq!(sensor.read().unwrap().temperature, 21.0);
+/// ```
+#[derive(Clone)]
+pub struct MockTemperatureSensor {
+    /// Scripted readings
+    readings: Arc<Vec<Dht11Data>>,
+    /// Index of the next reading
+    next: Arc<Mutex<usize>>,
+    /// Temperature monitoring loop state
+    monitor: MonitorState,
+}
+
+impl MockTemperatureSensor {
+    /// Create a mock sensor returning the given readings in a loop
+    pub fn new(readings: impl IntoIterator<Item = Dht11Data>) -> Self {
+        MockTemperatureSensor {
+            readings: Arc::new(readings.into_iter().collect()),
+            next: Arc::new(Mutex::new(0)),
+            monitor: MonitorState::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl TemperatureSensor for MockTemperatureSensor {
+    /// Return the next scripted reading, or an error if none were scripted
+    fn read(&self) -> Result<Dht11Data, SensorError> {
+        let mut next = self.next.lock().unwrap();
+        let data = self
+            .readings
+            .get(*next)
+            .copied()
+            .ok_or_else(|| SensorError::SensorError("No scripted readings".into()))?;
+        *next = (*next + 1) % self.readings.len();
+        Ok(data)
+    }
+
+    async fn read_async(&self) -> Result<Dht11Data, SensorError> {
+        self.read()
+    }
+
+    /// Start monitoring the scripted readings against a high threshold
+    fn start_monitoring<F>(
+        &self,
+        interval_ms: u64,
+        high_threshold: f32,
+        on_alert: F,
+    ) -> Result<JoinHandle<()>, SensorError>
+    where
+        F: Fn(Dht11Data) + Send + 'static,
+    {
+        self.monitor.spawn(
+            self.clone(),
+            interval_ms,
+            true,
+            high_threshold,
+            on_alert,
+            |_| {},
+        )
+    }
+
+    /// Stop monitoring
+    fn stop_monitoring(&self) {
+        self.monitor.stop();
+    }
+}
+
+/// Fire detector returning scripted flame states
+///
+/// Each read, and each monitoring check, consumes the next state, starting
+/// over after the last one. While monitoring, flame transitions are published
+/// to the event bus as `FlameDetected`/`FlameCleared` events, as
+/// [`FireSensor`](crate::sensors::fire::FireSensor) does.
+///
+/// # Example
+/// ```
+/// use env_monitor::events::{EventBus, SensorEvent};
+/// use env_monitor::sensors::FireDetector;
+/// use env_monitor::sensors::mock::MockFireSensor;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let bus = EventBus::new(16);
+///     let mut events = bus.subscribe();
+///     let sensor = MockFireSensor::new([false, true, true, false]).with_event_bus(bus);
+///
+///     let handle = sensor.start_monitoring(1).await.unwrap();
+///     assert!(matches!(events.recv().await.unwrap(), SensorEvent::FlameDetected(_)));
+///     assert!(matches!(events.recv().await.unwrap(), SensorEvent::FlameCleared(_)));
+///
+///     sensor.stop_monitoring();
+///     handle.await.unwrap();
+/// }
+/// ```
+pub struct MockFireSensor {
+    /// Scripted flame states
+    states: Arc<Vec<bool>>,
+    /// Index of the next state
+    next: Arc<Mutex<usize>>,
+    /// Monitoring active state
+    is_active: Arc<AtomicBool>,
+    /// Event bus that monitoring publishes flame transitions to
+    event_bus: Option<EventBus>,
+}
+
+impl MockFireSensor {
+    /// Create a mock sensor returning the given flame states in a loop
+    pub fn new(states: impl IntoIterator<Item = bool>) -> Self {
+        MockFireSensor {
+            states: Arc::new(states.into_iter().collect()),
+            next: Arc::new(Mutex::new(0)),
+            is_active: Arc::new(AtomicBool::new(false)),
+            event_bus: None,
+        }
+    }
+
+    /// Publish monitoring events to the given event bus
+    pub fn with_event_bus(mut self, bus: EventBus) -> Self {
+        self.event_bus = Some(bus);
+        self
+    }
+}
+
+// Consume the next scripted state as a reading
+fn next_reading(states: &[bool], next: &Mutex<usize>) -> Result<FireSensorData, SensorError> {
+    let mut next = next.lock().unwrap();
+    let flame_detected = *states
+        .get(*next)
+        .ok_or_else(|| SensorError::SensorError("No scripted flame states".into()))?;
+    *next = (*next + 1) % states.len();
+
+    Ok(FireSensorData {
+        flame_detected,
+        last_detection_timestamp: if flame_detected {
+            Some(TimestampUnit::Seconds.now(ClockFallback::Zero)?)
+        } else {
+            None
+        },
+    })
+}
+
+#[async_trait]
+impl FireDetector for MockFireSensor {
+    /// Return the next scripted state, or an error if none were scripted
+    fn read(&self) -> Result<FireSensorData, SensorError> {
+        next_reading(&self.states, &self.next)
+    }
+
+    async fn read_async(&self) -> Result<FireSensorData, SensorError> {
+        self.read()
+    }
+
+    /// Step through the scripted states every `check_interval_ms` until stopped
+    async fn start_monitoring(
+        &self,
+        check_interval_ms: u64,
+    ) -> Result<JoinHandle<()>, SensorError> {
+        if self.states.is_empty() {
+            return Err(SensorError::InitError("No scripted flame states".into()));
+        }
+
+        self.is_active.store(true, Ordering::Relaxed);
+        let states = self.states.clone();
+        let next = self.next.clone();
+        let is_active = self.is_active.clone();
+        let event_bus = self.event_bus.clone();
+
+        Ok(tokio::spawn(async move {
+            let mut was_detected = false;
+
+            while is_active.load(Ordering::Relaxed) {
+                if let Ok(data) = next_reading(&states, &next)
+                    && data.flame_detected != was_detected
+                {
+                    was_detected = data.flame_detected;
+                    if let Some(bus) = &event_bus {
+                        bus.publish(if data.flame_detected {
+                            SensorEvent::FlameDetected(data)
+                        } else {
+                            SensorEvent::FlameCleared(data)
+                        });
+                    }
+                }
+
+                sleep(Duration::from_millis(check_interval_ms)).await;
+            }
+        }))
+    }
+
+    fn stop_monitoring(&self) {
+        self.is_active.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Simulated GPIO controller for running the hardware sensors without a Pi
+///
+/// Hand it to a sensor's `with_gpio` and the sensor's own code runs against
+/// simulated pins: [`set_level`](MockGpio::set_level) drives the level an
+/// input reads, [`writes`](MockGpio::writes) records what was written to an
+/// output and when, and [`script_response`](MockGpio::script_response) plays
+/// back the waveform a single-wire sensor sends after its start signal. Pins
+/// are claimed like on the real controller, so acquiring a pin that is still
+/// held fails with `PinUsed`. Clones share the same pins.
+///
+/// # Example
+/// ```
+/// use env_monitor::sensors::FireDetector;
+/// use env_monitor::sensors::fire::FireSensor;
+/// use env_monitor::sensors::mock::MockGpio;
+/// use rppal::gpio::Level;
+///
+/// let gpio = MockGpio::new();
+/// let sensor = FireSensor::new(27, 22, true).with_gpio(gpio.clone());
+///
+/// gpio.set_level(27, Level::High);
+/// assert!(sensor.read().unwrap().flame_detected);
+///
+/// gpio.set_level(27, Level::Low);
+/// assert!(!sensor.read().unwrap().flame_detected);
+/// assert_eq!(gpio.reads(27), 2);
+/// ```
+#[derive(Clone, Default)]
+pub struct MockGpio {
+    /// State of every pin touched so far, by BCM number
+    pins: Arc<Mutex<HashMap<u8, MockPin>>>,
+}
+
+/// Edge callback registered by interrupt-driven monitoring
+type EdgeCallback = Box<dyn FnMut(Level) + Send>;
+
+// State of one simulated pin
+#[derive(Default)]
+struct MockPin {
+    /// Level driven onto the pin from outside (floating if None)
+    level: Option<Level>,
+    /// Pull resistor deciding what a floating pin reads
+    pull: Pull,
+    /// Whether a sensor currently holds the pin
+    claimed: bool,
+    /// Levels written to the pin, with the time of each write
+    writes: Vec<(Instant, Level)>,
+    /// Number of times the pin was read
+    reads: usize,
+    /// Waveform played each time the pin is released to the sensor
+    response: Vec<(Level, Duration)>,
+    /// Position in the waveform currently playing
+    playback: Option<Playback>,
+    /// Callback notified of edges caused by `set_level`
+    on_edge: Option<EdgeCallback>,
+}
+
+// Position in a playing response waveform
+struct Playback {
+    /// Index of the current segment
+    segment: usize,
+    /// When the current segment started
+    started: Instant,
+    /// Whether the current segment has been read at least once
+    seen: bool,
+}
+
+impl MockPin {
+    // Level a read returns when nothing is scripted
+    fn idle_level(&self) -> Level {
+        self.level.unwrap_or(Level::from(self.pull == Pull::Up))
+    }
+
+    // Advance the playing response and return the level it drives, if any
+    //
+    // A segment only ends once it has been read, so a reader preempted for
+    // longer than a segment still sees every level in order.
+    fn response_level(&mut self) -> Option<Level> {
+        let playback = self.playback.as_mut()?;
+        loop {
+            let Some(&(level, duration)) = self.response.get(playback.segment) else {
+                self.playback = None;
+                return None;
+            };
+            if playback.seen && playback.started.elapsed() >= duration {
+                playback.segment += 1;
+                playback.started = Instant::now();
+                playback.seen = false;
+                continue;
+            }
+            playback.seen = true;
+            return Some(level);
+        }
+    }
+}
+
+impl MockGpio {
+    /// Create a controller with every pin floating and unclaimed
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drive the level a pin reads, as a connected sensor would
+    ///
+    /// Interrupt-driven monitoring watching the pin is notified if the level
+    /// changes. A pin that was never driven reads high with a pull-up and low
+    /// otherwise.
+    pub fn set_level(&self, pin: u8, level: Level) {
+        let mut pins = self.pins.lock().unwrap();
+        let state = pins.entry(pin).or_default();
+        let changed = state.idle_level() != level;
+        state.level = Some(level);
+
+        // Notify outside the lock, so the callback may use the controller
+        let Some(mut on_edge) = state.on_edge.take().filter(|_| changed) else {
+            return;
+        };
+        drop(pins);
+        on_edge(level);
+
+        let mut pins = self.pins.lock().unwrap();
+        let state = pins.entry(pin).or_default();
+        if state.claimed && state.on_edge.is_none() {
+            state.on_edge = Some(on_edge);
+        }
+    }
+
+    /// Levels written to a pin so far, with the time of each write
+    pub fn writes(&self, pin: u8) -> Vec<(Instant, Level)> {
+        self.pins
+            .lock()
+            .unwrap()
+            .get(&pin)
+            .map(|state| state.writes.clone())
+            .unwrap_or_default()
+    }
+
+    /// Last level written to a pin, if it was ever written
+    pub fn output_level(&self, pin: u8) -> Option<Level> {
+        self.pins
+            .lock()
+            .unwrap()
+            .get(&pin)
+            .and_then(|state| state.writes.last())
+            .map(|&(_, level)| level)
+    }
+
+    /// Number of times a pin has been read
+    pub fn reads(&self, pin: u8) -> usize {
+        self.pins
+            .lock()
+            .unwrap()
+            .get(&pin)
+            .map_or(0, |state| state.reads)
+    }
+
+    /// Whether a sensor currently holds the pin
+    pub fn is_claimed(&self, pin: u8) -> bool {
+        self.pins
+            .lock()
+            .unwrap()
+            .get(&pin)
+            .is_some_and(|state| state.claimed)
+    }
+
+    /// Play `waveform` every time a single-wire sensor releases the pin
+    ///
+    /// After sending its start signal, a DHT sensor switches the pin to input
+    /// and times the pulses the sensor answers with. From that moment the pin
+    /// reads each `(level, duration)` segment in turn, then falls back to the
+    /// level set with [`set_level`](MockGpio::set_level). Every segment is read
+    /// at least once however long the reader is held up, so the sequence of
+    /// levels is deterministic even on a loaded machine.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::error::SensorError;
+    /// use env_monitor::sensors::TemperatureSensor;
+    /// use env_monitor::sensors::dht11::Dht11Sensor;
+    /// use env_monitor::sensors::mock::MockGpio;
+    ///
+    /// // A sensor that falls silent after the first 20 bits, leaving the line low
+    /// let gpio = MockGpio::new();
+    /// let mut waveform = MockGpio::dht_waveform([45, 0, 23, 0, 68]);
+    /// waveform.truncate(2 + 2 * 20);
+    /// gpio.script_response(17, waveform);
+    ///
+    /// let sensor = Dht11Sensor::new(17).with_gpio(gpio);
+    /// match sensor.read() {
+    ///     Err(SensorError::Timeout { bits_read, .. }) => assert_eq!(bits_read, 20),
+    ///     other => panic!("expected a timeout, got {:?}", other),
+    /// }
+    /// ```
+    pub fn script_response(&self, pin: u8, waveform: impl IntoIterator<Item = (Level, Duration)>) {
+        let mut pins = self.pins.lock().unwrap();
+        pins.entry(pin).or_default().response = waveform.into_iter().collect();
+    }
+
+    /// Waveform a DHT11 or DHT22 sends for the given 5-byte frame
+    ///
+    /// Starts with the response pulse (80µs low, 80µs high), followed by each
+    /// bit as 50µs low and 27µs (0) or 70µs (1) high, and ends with the final
+    /// 50µs low. Truncate it to simulate a sensor that stops mid-frame.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::mock::MockGpio;
+    ///
+    /// let waveform = MockGpio::dht_waveform([45, 0, 23, 0, 68]);
+    /// assert_eq!(waveform.len(), 2 + 2 * 40 + 1);
+    /// ```
+    pub fn dht_waveform(frame: [u8; 5]) -> Vec<(Level, Duration)> {
+        let mut waveform = vec![
+            (Level::Low, Duration::from_micros(80)),
+            (Level::High, Duration::from_micros(80)),
+        ];
+        for i in 0..40 {
+            let bit = frame[i / 8] >> (7 - i % 8) & 1;
+            waveform.push((Level::Low, Duration::from_micros(50)));
+            waveform.push((
+                Level::High,
+                Duration::from_micros(if bit == 1 { 70 } else { 27 }),
+            ));
+        }
+        waveform.push((Level::Low, Duration::from_micros(50)));
+        waveform
+    }
+
+    /// Claim a pin as a sensor would, failing like rppal if it is already held
+    ///
+    /// The sensors do this through their GPIO controller; claiming directly
+    /// lets tests and benchmarks drive a pin the way a sensor's loop does. The
+    /// pin is released when the returned line is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::Pull;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use rppal::gpio::Level;
+    ///
+    /// let gpio = MockGpio::new();
+    /// let mut buzzer = gpio.claim(22, Pull::None).unwrap();
+    /// assert!(gpio.claim(22, Pull::None).is_err());
+    ///
+    /// buzzer.write(Level::High);
+    /// assert_eq!(gpio.output_level(22), Some(Level::High));
+    ///
+    /// drop(buzzer);
+    /// assert!(!gpio.is_claimed(22));
+    /// ```
+    pub fn claim(&self, pin: u8, pull: Pull) -> Result<MockLine, SensorError> {
+        let mut pins = self.pins.lock().unwrap();
+        let state = pins.entry(pin).or_default();
+        if state.claimed {
+            return Err(SensorError::GpioError(gpio::Error::PinUsed(pin)));
+        }
+        state.claimed = true;
+        state.pull = pull;
+        state.playback = None;
+
+        Ok(MockLine {
+            gpio: self.clone(),
+            pin,
+        })
+    }
+
+    // Run `f` on the state of a pin
+    fn with_pin<T>(&self, pin: u8, f: impl FnOnce(&mut MockPin) -> T) -> T {
+        f(self.pins.lock().unwrap().entry(pin).or_default())
+    }
+}
+
+/// Pin of a [`MockGpio`] held by a sensor, released on drop
+pub struct MockLine {
+    /// Controller the pin belongs to
+    gpio: MockGpio,
+    /// BCM number of the pin
+    pin: u8,
+}
+
+impl MockLine {
+    /// Read the pin's level, counting the read
+    pub fn read(&self) -> Level {
+        self.gpio.with_pin(self.pin, |state| {
+            state.reads += 1;
+            state.response_level().unwrap_or_else(|| state.idle_level())
+        })
+    }
+
+    /// Write a level to the pin, recording it with the time of the write
+    pub fn write(&mut self, level: Level) {
+        self.gpio.with_pin(self.pin, |state| {
+            state.writes.push((Instant::now(), level));
+        });
+    }
+
+    // Last level written, or low if never written
+    pub(crate) fn output_level(&self) -> Level {
+        self.gpio.output_level(self.pin).unwrap_or(Level::Low)
+    }
+
+    // Start playing the scripted response, as the pin has been released to the sensor
+    pub(crate) fn start_response(&mut self) {
+        self.gpio.with_pin(self.pin, |state| {
+            state.playback = Some(Playback {
+                segment: 0,
+                started: Instant::now(),
+                seen: false,
+            });
+        });
+    }
+
+    pub(crate) fn watch_edges(&mut self, on_edge: EdgeCallback) {
+        self.gpio
+            .with_pin(self.pin, |state| state.on_edge = Some(on_edge));
+    }
+}
+
+impl Drop for MockLine {
+    fn drop(&mut self) {
+        self.gpio.with_pin(self.pin, |state| {
+            state.claimed = false;
+            state.playback = None;
+            state.on_edge = None;
+        });
+    }
+}
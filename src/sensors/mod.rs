@@ -1,8 +1,24 @@
 //! Sensor implementations and traits
 
+pub mod catalog;
+pub mod debounce;
+pub mod detect;
 pub mod dht11;
+pub mod dht22;
+pub mod ds18b20;
+pub mod filtered;
 pub mod fire;
+pub mod fire_array;
+pub mod fire_group;
+pub mod gpio;
+#[cfg(feature = "mock")]
+pub mod mock;
+mod monitoring;
+pub mod mq2;
+pub mod scan;
+#[cfg(feature = "simulate")]
+mod simulate;
 pub mod traits;
 
 // Re-export traits
-pub use traits::{FireDetector, TemperatureSensor};
+pub use traits::{FireDetector, GasDetector, TemperatureSensor};
@@ -0,0 +1,103 @@
+//! Monitoring run state shared by the sensors, and the temperature threshold loop
+
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::{Duration, sleep};
+use tokio_util::sync::CancellationToken;
+
+use crate::error::SensorError;
+use crate::sensors::dht11::Dht11Data;
+use crate::sensors::traits::TemperatureSensor;
+
+// Monitoring state of a sensor, shared by the sensor's clones
+//
+// Each run gets its own cancellation token, so a loop stopped mid-sleep
+// exits even if monitoring is started again before it wakes up.
+#[derive(Clone, Default)]
+pub(crate) struct MonitorState {
+    /// Token of the running loop, None or cancelled while none runs
+    running: Arc<Mutex<Option<CancellationToken>>>,
+}
+
+impl MonitorState {
+    // Reserve the sensor for a new monitoring run, failing if one is already running
+    //
+    // `stop` cancels the returned token. A run that ends on its own, or fails
+    // to start after reserving, must cancel it so monitoring can start again.
+    pub(crate) fn begin(&self) -> Result<CancellationToken, SensorError> {
+        let mut running = self.running.lock().unwrap();
+        if running.as_ref().is_some_and(|token| !token.is_cancelled()) {
+            return Err(SensorError::SensorError(
+                "Monitoring is already running".into(),
+            ));
+        }
+        Ok(running.insert(CancellationToken::new()).clone())
+    }
+
+    // Spawn the monitoring loop, failing if one is already running
+    //
+    // Reads `sensor` every `interval_ms` and calls `on_alert` once per crossing
+    // of the temperature above `high_threshold`. `on_reading` sees every
+    // successful reading after the threshold check, for sensor-specific alerts.
+    pub(crate) fn spawn<S, F, H>(
+        &self,
+        sensor: S,
+        interval_ms: u64,
+        immediate_first: bool,
+        high_threshold: f32,
+        on_alert: F,
+        mut on_reading: H,
+    ) -> Result<JoinHandle<()>, SensorError>
+    where
+        S: TemperatureSensor + 'static,
+        F: Fn(Dht11Data) + Send + 'static,
+        H: FnMut(Dht11Data) + Send + 'static,
+    {
+        let token = self.begin()?;
+        let interval = Duration::from_millis(interval_ms);
+
+        Ok(tokio::spawn(async move {
+            // Mark the run finished however the task ends
+            let _finished = token.clone().drop_guard();
+
+            if !immediate_first {
+                tokio::select! {
+                    _ = token.cancelled() => return,
+                    _ = sleep(interval) => {}
+                }
+            }
+
+            // Whether the previous reading was above the threshold
+            let mut above = false;
+
+            loop {
+                match sensor.read_async().await {
+                    Ok(data) => {
+                        if data.temperature > high_threshold {
+                            if !above {
+                                on_alert(data);
+                            }
+                            above = true;
+                        } else {
+                            above = false;
+                        }
+                        on_reading(data);
+                    }
+                    Err(e) => log::warn!("Temperature monitoring read failed: {}", e),
+                }
+
+                tokio::select! {
+                    _ = token.cancelled() => break,
+                    _ = sleep(interval) => {}
+                }
+            }
+        }))
+    }
+
+    // Stop the running loop, if any
+    pub(crate) fn stop(&self) {
+        if let Some(token) = self.running.lock().unwrap().take() {
+            token.cancel();
+        }
+    }
+}
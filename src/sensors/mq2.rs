@@ -0,0 +1,308 @@
+//! MQ-2 smoke and gas sensor implementation
+
+use async_trait::async_trait;
+use rppal::gpio::Level;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::task::{self, JoinHandle};
+use tokio::time::Duration;
+
+use crate::alarm::{AlarmBuzzer, AlarmSource};
+use crate::clock::{ClockFallback, TimestampUnit};
+use crate::error::SensorError;
+use crate::sensors::fire::Pull;
+use crate::sensors::gpio::GpioHandle;
+use crate::sensors::traits::GasDetector;
+
+/// Gas sensor data structure containing detection status and timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GasSensorData {
+    /// Whether smoke or gas above the module's threshold is detected
+    pub gas_detected: bool,
+    /// Unix timestamp in seconds of the last detection (if detected)
+    pub last_detection_timestamp: Option<u64>,
+}
+
+/// MQ-2 smoke/gas sensor read through its digital output, with buzzer support
+///
+/// The module compares the analog signal against the threshold set with its
+/// potentiometer and drives the digital output accordingly; most modules pull
+/// it low when gas is present. Reading the analog output would need an ADC and
+/// is not supported. The heater needs a minute or so after power-up before
+/// readings are meaningful.
+///
+/// The alarm uses the gas pattern of [`AlarmSource::Gas`] by default, so it
+/// sounds different from a fire alarm on the same kind of buzzer.
+pub struct Mq2Sensor {
+    /// GPIO pin number connected to the digital output
+    digital_pin: u8,
+    /// GPIO pin number connected to the buzzer
+    buzzer_pin: u8,
+    /// Sensor logic (true = high level indicates gas, false = low level indicates gas)
+    high_active: bool,
+    /// Buzzer logic (true = sounds on high level, false = sounds on low level)
+    buzzer_active_high: bool,
+    /// Alarm tone frequency in Hz
+    alarm_frequency_hz: u32,
+    /// Duration of each alarm tone in milliseconds
+    alarm_duration_ms: u64,
+    /// Monitoring active state, cleared by `stop_monitoring`
+    is_active: Arc<AtomicBool>,
+    /// GPIO controller the pins are acquired from
+    gpio: GpioHandle,
+}
+
+impl Mq2Sensor {
+    /// Create a new MQ-2 sensor instance
+    ///
+    /// # Arguments
+    /// * `digital_pin` - GPIO pin number connected to the module's digital output
+    /// * `buzzer_pin` - GPIO pin number connected to the buzzer
+    /// * `high_active` - Sensor logic (true if high level indicates gas, false for the usual low level)
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::mq2::Mq2Sensor;
+    ///
+    /// // Typical module pulling its digital output low on gas
+    /// let sensor = Mq2Sensor::new(5, 22, false);
+    /// ```
+    pub fn new(digital_pin: u8, buzzer_pin: u8, high_active: bool) -> Self {
+        let (alarm_frequency_hz, alarm_duration_ms) = AlarmSource::Gas.pattern();
+        Mq2Sensor {
+            digital_pin,
+            buzzer_pin,
+            high_active,
+            buzzer_active_high: false,
+            alarm_frequency_hz,
+            alarm_duration_ms,
+            is_active: Arc::new(AtomicBool::new(false)),
+            gpio: GpioHandle::default(),
+        }
+    }
+
+    /// Acquire the pins from the given GPIO controller
+    ///
+    /// Accepts an opened `rppal::gpio::Gpio` (or `Arc<Gpio>`) to share one
+    /// controller between sensors, or a `MockGpio` with the `mock` feature. By
+    /// default the sensor opens the controller itself on first use.
+    pub fn with_gpio(mut self, gpio: impl Into<GpioHandle>) -> Self {
+        self.gpio = gpio.into();
+        self
+    }
+
+    /// Set the buzzer logic (true if the buzzer sounds on high level)
+    ///
+    /// Defaults to false, matching the common active-low buzzer modules.
+    pub fn with_buzzer_active_high(mut self, active_high: bool) -> Self {
+        self.buzzer_active_high = active_high;
+        self
+    }
+
+    /// Set the alarm tone sounded on the buzzer
+    ///
+    /// A frequency of 0 keeps the buzzer silent; frequencies above
+    /// [`MAX_TONE_FREQUENCY_HZ`](crate::alarm::MAX_TONE_FREQUENCY_HZ) (500kHz)
+    /// are clamped to it.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::mq2::Mq2Sensor;
+    ///
+    /// let sensor = Mq2Sensor::new(5, 22, false).with_alarm_tone(1500, 300);
+    /// ```
+    pub fn with_alarm_tone(mut self, frequency_hz: u32, duration_ms: u64) -> Self {
+        self.alarm_frequency_hz = frequency_hz;
+        self.alarm_duration_ms = duration_ms;
+        self
+    }
+
+    /// Whether the sensor is configured as high level active
+    pub fn is_high_active(&self) -> bool {
+        self.high_active
+    }
+}
+
+// Read the digital output once and build the reading from it
+fn read_digital(
+    gpio: &GpioHandle,
+    digital_pin: u8,
+    high_active: bool,
+) -> Result<GasSensorData, SensorError> {
+    let output = gpio.input(digital_pin, Pull::None)?;
+    let active_level = if high_active { Level::High } else { Level::Low };
+    let gas_detected = output.read() == active_level;
+
+    let timestamp = if gas_detected {
+        Some(TimestampUnit::Seconds.now(ClockFallback::default())?)
+    } else {
+        None
+    };
+
+    Ok(GasSensorData {
+        gas_detected,
+        last_detection_timestamp: timestamp,
+    })
+}
+
+#[async_trait]
+impl GasDetector for Mq2Sensor {
+    /// Synchronously read gas sensor status
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::GasDetector;
+    /// use env_monitor::sensors::mq2::Mq2Sensor;
+    ///
+    /// let sensor = Mq2Sensor::new(5, 22, false);
+    /// match sensor.read() {
+    ///     Ok(data) => println!("Gas detected: {}", data.gas_detected),
+    ///     Err(e) => println!("Read failed: {}", e),
+    /// }
+    /// ```
+    ///
+    /// Against a `MockGpio` (with the `mock` feature), gas is reported only at
+    /// the module's active level:
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::sensors::GasDetector;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use env_monitor::sensors::mq2::Mq2Sensor;
+    /// use rppal::gpio::Level;
+    ///
+    /// let gpio = MockGpio::new();
+    /// let sensor = Mq2Sensor::new(5, 22, false).with_gpio(gpio.clone());
+    ///
+    /// // Below the potentiometer threshold the active-low output stays high
+    /// gpio.set_level(5, Level::High);
+    /// let data = sensor.read().unwrap();
+    /// assert!(!data.gas_detected);
+    /// assert_eq!(data.last_detection_timestamp, None);
+    ///
+    /// gpio.set_level(5, Level::Low);
+    /// let data = sensor.read().unwrap();
+    /// assert!(data.gas_detected);
+    /// assert!(data.last_detection_timestamp.is_some());
+    ///
+    /// // A high-active module reports the opposite level as gas
+    /// let sensor = Mq2Sensor::new(5, 22, true).with_gpio(gpio.clone());
+    /// assert!(!sensor.read().unwrap().gas_detected);
+    /// # }
+    /// ```
+    fn read(&self) -> Result<GasSensorData, SensorError> {
+        read_digital(&self.gpio, self.digital_pin, self.high_active)
+    }
+
+    /// Asynchronously read gas sensor status
+    async fn read_async(&self) -> Result<GasSensorData, SensorError> {
+        let gpio = self.gpio.clone();
+        let digital_pin = self.digital_pin;
+        let high_active = self.high_active;
+
+        // Execute the read operation in a blocking task
+        task::spawn_blocking(move || read_digital(&gpio, digital_pin, high_active))
+            .await
+            .map_err(|e| SensorError::SensorError(format!("Task join error: {}", e)))?
+    }
+
+    /// Start monitoring, sounding the buzzer while gas is detected
+    ///
+    /// # Example
+    /// ```no_run
+    /// use env_monitor::sensors::GasDetector;
+    /// use env_monitor::sensors::mq2::Mq2Sensor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let sensor = Mq2Sensor::new(5, 22, false);
+    ///     let handle = sensor.start_monitoring(500).await?;
+    ///
+    ///     // Do other things...
+    ///
+    ///     sensor.stop_monitoring();
+    ///     handle.await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Against a `MockGpio` (with the `mock` feature), the alarm follows the
+    /// gas detection on and off:
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::sensors::GasDetector;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use env_monitor::sensors::mq2::Mq2Sensor;
+    /// use rppal::gpio::Level;
+    /// use std::time::Duration;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let gpio = MockGpio::new();
+    /// gpio.set_level(5, Level::High);
+    /// let sensor = Mq2Sensor::new(5, 22, false)
+    ///     .with_alarm_tone(1000, 10)
+    ///     .with_gpio(gpio.clone());
+    /// let handle = sensor.start_monitoring(5).await.unwrap();
+    ///
+    /// // Clean air: the active-low buzzer is held high
+    /// tokio::time::sleep(Duration::from_millis(30)).await;
+    /// assert!(gpio.writes(22).iter().all(|&(_, level)| level == Level::High));
+    ///
+    /// // Gas: the buzzer sounds
+    /// gpio.set_level(5, Level::Low);
+    /// tokio::time::sleep(Duration::from_millis(50)).await;
+    /// assert!(gpio.writes(22).iter().any(|&(_, level)| level == Level::Low));
+    ///
+    /// // Cleared: once the tone in progress ends, the buzzer stays silent
+    /// gpio.set_level(5, Level::High);
+    /// tokio::time::sleep(Duration::from_millis(50)).await;
+    /// let sounded = gpio.writes(22).len();
+    /// tokio::time::sleep(Duration::from_millis(30)).await;
+    /// assert!(gpio.writes(22)[sounded..].iter().all(|&(_, level)| level == Level::High));
+    ///
+    /// sensor.stop_monitoring();
+    /// handle.await.unwrap();
+    /// assert_eq!(gpio.output_level(22), Some(Level::High));
+    /// # });
+    /// # }
+    /// ```
+    async fn start_monitoring(
+        &self,
+        check_interval_ms: u64,
+    ) -> Result<JoinHandle<()>, SensorError> {
+        log::info!("Starting gas monitoring");
+
+        let output = self.gpio.input(self.digital_pin, Pull::None)?;
+        let buzzer = AlarmBuzzer::new(
+            self.gpio.output(self.buzzer_pin)?,
+            self.buzzer_active_high,
+            self.alarm_frequency_hz,
+            self.alarm_duration_ms,
+        );
+        self.is_active.store(true, Ordering::Relaxed);
+
+        let active_level = if self.high_active {
+            Level::High
+        } else {
+            Level::Low
+        };
+
+        Ok(buzzer.spawn_loop(
+            Duration::from_millis(check_interval_ms),
+            self.is_active.clone(),
+            move || output.read() == active_level,
+            |gas_detected| {
+                if gas_detected {
+                    log::warn!("Gas detected!");
+                } else {
+                    log::info!("Gas cleared");
+                }
+            },
+        ))
+    }
+
+    /// Stop gas monitoring
+    fn stop_monitoring(&self) {
+        self.is_active.store(false, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,81 @@
+//! Deterministic scan cycles over several sensors with a shared deadline
+
+use tokio::time::{Duration, Instant, timeout};
+
+use crate::error::SensorError;
+use crate::sensors::dht11::Dht11Data;
+use crate::sensors::traits::TemperatureSensor;
+
+/// Read sensors in order within a shared overall deadline
+///
+/// Each sensor gets an equal share of the time remaining when its turn comes:
+/// the first of `n` sensors gets `deadline / n`, and time left unused by a fast
+/// sensor rolls over to the ones after it. A sensor exceeding its slice yields
+/// `SensorError::Timeout` and the scan moves on, so one slow sensor cannot
+/// starve the rest; sensors whose turn comes after the deadline has passed
+/// also yield `Timeout`. Results are returned in sensor order.
+///
+/// A timed-out read's blocking task is not interrupted; it finishes in the
+/// background and its result is discarded.
+///
+/// # Example
+/// ```
+/// use async_trait::async_trait;
+/// use env_monitor::Dht11Data;
+/// use env_monitor::error::SensorError;
+/// use env_monitor::sensors::TemperatureSensor;
+/// use env_monitor::sensors::scan::scan;
+/// use std::time::Duration;
+///
+/// struct FixedSensor(Duration);
+///
+/// #[async_trait]
+/// impl TemperatureSensor for FixedSensor {
+///     fn read(&self) -> Result<Dht11Data, SensorError> {
+///         Ok(Dht11Data { temperature: 21.0, humidity: 50.0, timestamp: 0 })
+///     }
+///
+///     async fn read_async(&self) -> Result<Dht11Data, SensorError> {
+///         tokio::time::sleep(self.0).await;
+///         self.read()
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let slow = FixedSensor(Duration::from_secs(5));
+///     let fast = FixedSensor(Duration::from_millis(1));
+///
+///     let results = scan(&[&slow, &fast, &fast], Duration::from_millis(300)).await;
+///     assert!(matches!(results[0], Err(SensorError::Timeout { .. })));
+///     assert!(results[1].is_ok() && results[2].is_ok());
+/// }
+/// ```
+pub async fn scan(
+    sensors: &[&dyn TemperatureSensor],
+    deadline: Duration,
+) -> Vec<Result<Dht11Data, SensorError>> {
+    let end = Instant::now() + deadline;
+    let mut results = Vec::with_capacity(sensors.len());
+
+    for (i, sensor) in sensors.iter().enumerate() {
+        let remaining = end.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            results.push(Err(SensorError::timeout(
+                "Scan deadline reached before sensor's turn",
+            )));
+            continue;
+        }
+
+        let slice = remaining / (sensors.len() - i) as u32;
+        let result = match timeout(slice, sensor.read_async()).await {
+            Ok(result) => result,
+            Err(_) => Err(SensorError::timeout(
+                "Sensor read exceeded its scan time slice",
+            )),
+        };
+        results.push(result);
+    }
+
+    results
+}
@@ -0,0 +1,63 @@
+//! Simulated sensor signals used in place of GPIO with the `simulate` feature
+
+use std::f32::consts::TAU;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/// Period of the simulated temperature swing
+const TEMPERATURE_PERIOD: Duration = Duration::from_secs(300);
+
+/// Period of the simulated humidity swing
+const HUMIDITY_PERIOD: Duration = Duration::from_secs(420);
+
+/// The simulated flame burns for the last `FLAME_DURATION` of every `FLAME_PERIOD`
+const FLAME_PERIOD: Duration = Duration::from_secs(60);
+const FLAME_DURATION: Duration = Duration::from_secs(10);
+
+// Time since the first simulated read, so every run follows the same schedule
+fn elapsed() -> Duration {
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed()
+}
+
+// Position within a period as a fraction of a full turn
+fn phase(period: Duration) -> f32 {
+    (elapsed().as_secs_f32() % period.as_secs_f32()) / period.as_secs_f32() * TAU
+}
+
+// High-level bit durations of a DHT11 frame, drifting around 24°C and 55% RH
+pub(crate) fn dht11_bit_durations() -> [Duration; 40] {
+    let temperature = 24.0 + 4.0 * phase(TEMPERATURE_PERIOD).sin();
+    let humidity = 55.0 + 10.0 * phase(HUMIDITY_PERIOD).sin();
+
+    // Whole and tenth parts, as the sensor sends them
+    let split = |value: f32| {
+        let tenths = (value * 10.0).round() as u16;
+        ((tenths / 10) as u8, (tenths % 10) as u8)
+    };
+    let (humidity_int, humidity_dec) = split(humidity);
+    let (temperature_int, temperature_dec) = split(temperature);
+    let mut frame = [
+        humidity_int,
+        humidity_dec,
+        temperature_int,
+        temperature_dec,
+        0,
+    ];
+    frame[4] = frame[..4]
+        .iter()
+        .fold(0u8, |sum, byte| sum.wrapping_add(*byte));
+
+    let mut durations = [Duration::ZERO; 40];
+    for (i, duration) in durations.iter_mut().enumerate() {
+        let bit = frame[i / 8] >> (7 - i % 8) & 1;
+        *duration = Duration::from_micros(if bit == 1 { 70 } else { 27 });
+    }
+    durations
+}
+
+// Whether the simulated flame is currently burning
+pub(crate) fn flame_present() -> bool {
+    elapsed().as_secs_f32() % FLAME_PERIOD.as_secs_f32()
+        >= (FLAME_PERIOD - FLAME_DURATION).as_secs_f32()
+}
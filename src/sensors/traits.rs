@@ -1,10 +1,15 @@
 //! Sensor trait definitions
 
 use crate::error::SensorError;
-use crate::sensors::{dht11::Dht11Data, fire::FireSensorData};
+use crate::sensors::{dht11::Dht11Data, fire::FireSensorData, mq2::GasSensorData};
 use async_trait::async_trait;
+use tokio::task::JoinHandle;
 
 /// Temperature and humidity sensor trait
+///
+/// This is the single interface shared by every temperature source (DHT11,
+/// DHT22, DS18B20), so they can be used interchangeably. Sensors without a
+/// humidity element report `humidity` as `f32::NAN`.
 #[async_trait]
 pub trait TemperatureSensor: Send + Sync {
     /// Synchronously read temperature and humidity data
@@ -12,6 +17,78 @@ pub trait TemperatureSensor: Send + Sync {
 
     /// Asynchronously read temperature and humidity data
     async fn read_async(&self) -> Result<Dht11Data, SensorError>;
+
+    /// Start monitoring the temperature against a high threshold
+    ///
+    /// Reads the sensor every `interval_ms` in a background task and calls
+    /// `on_alert` with the reading whenever the temperature crosses above
+    /// `high_threshold`. The callback fires once per crossing, not on every
+    /// reading above the threshold; it is re-armed once a reading is at or
+    /// below the threshold again. Failed reads are logged as warnings and
+    /// skipped. The callback runs on the monitoring task and delays the next
+    /// read until it returns.
+    ///
+    /// Only one loop runs per sensor: starting monitoring again before
+    /// [`stop_monitoring`](TemperatureSensor::stop_monitoring) returns an
+    /// error. Sensors that can't be monitored return an error by default.
+    ///
+    /// # Returns
+    /// Handle of the monitoring task, which exits after `stop_monitoring`
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "mock")] {
+    /// use env_monitor::Dht11Data;
+    /// use env_monitor::sensors::TemperatureSensor;
+    /// use env_monitor::sensors::mock::MockTemperatureSensor;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let reading = |temperature| Dht11Data { temperature, humidity: 40.0, timestamp: 0 };
+    /// let sensor = MockTemperatureSensor::new([20.0, 30.0, 31.0, 20.0, 32.0].map(reading));
+    ///
+    /// let (tx, mut alerts) = tokio::sync::mpsc::unbounded_channel();
+    /// let handle = sensor
+    ///     .start_monitoring(1, 25.0, move |data| {
+    ///         let _ = tx.send(data.temperature);
+    ///     })
+    ///     .unwrap();
+    ///
+    /// // 31°C stays above the threshold, so only the crossings alert
+    /// assert_eq!(alerts.recv().await, Some(30.0));
+    /// assert_eq!(alerts.recv().await, Some(32.0));
+    ///
+    /// // A second loop on the same sensor is refused
+    /// assert!(sensor.start_monitoring(1, 25.0, |_| {}).is_err());
+    ///
+    /// sensor.stop_monitoring();
+    /// handle.await.unwrap();
+    /// assert!(sensor.start_monitoring(1, 25.0, |_| {}).is_ok());
+    /// sensor.stop_monitoring();
+    /// # });
+    /// # }
+    /// ```
+    fn start_monitoring<F>(
+        &self,
+        interval_ms: u64,
+        high_threshold: f32,
+        on_alert: F,
+    ) -> Result<JoinHandle<()>, SensorError>
+    where
+        F: Fn(Dht11Data) + Send + 'static,
+        Self: Sized,
+    {
+        let _ = (interval_ms, high_threshold, on_alert);
+        Err(SensorError::SensorError(
+            "Monitoring is not supported by this sensor".into(),
+        ))
+    }
+
+    /// Stop temperature monitoring
+    ///
+    /// The loop exits without another read; await the handle returned by
+    /// [`start_monitoring`](TemperatureSensor::start_monitoring) to wait for
+    /// it. Does nothing if monitoring isn't running or supported.
+    fn stop_monitoring(&self) {}
 }
 
 /// Fire detection sensor trait
@@ -24,8 +101,36 @@ pub trait FireDetector: Send + Sync {
     async fn read_async(&self) -> Result<FireSensorData, SensorError>;
 
     /// Start monitoring for fire with the given check interval
-    async fn start_monitoring(&self, check_interval_ms: u64) -> Result<(), SensorError>;
+    ///
+    /// Returns the handle of the background monitoring task. Awaiting it after
+    /// `stop_monitoring` guarantees the loop has exited and released its GPIO
+    /// pins (with the buzzer silenced) before the program moves on.
+    async fn start_monitoring(&self, check_interval_ms: u64)
+    -> Result<JoinHandle<()>, SensorError>;
 
     /// Stop monitoring for fire
     fn stop_monitoring(&self);
 }
+
+/// Smoke and gas detection sensor trait
+///
+/// The counterpart of [`FireDetector`] for sensors that catch smoldering fires
+/// before a flame is visible.
+#[async_trait]
+pub trait GasDetector: Send + Sync {
+    /// Synchronously read gas detector status
+    fn read(&self) -> Result<GasSensorData, SensorError>;
+
+    /// Asynchronously read gas detector status
+    async fn read_async(&self) -> Result<GasSensorData, SensorError>;
+
+    /// Start monitoring for gas with the given check interval
+    ///
+    /// Returns the handle of the background monitoring task, which silences
+    /// the buzzer before exiting.
+    async fn start_monitoring(&self, check_interval_ms: u64)
+    -> Result<JoinHandle<()>, SensorError>;
+
+    /// Stop monitoring for gas
+    fn stop_monitoring(&self);
+}
@@ -0,0 +1,136 @@
+//! Graceful shutdown coordination across monitoring loops
+
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, watch};
+
+/// Shared handle that stops every registered monitoring loop at once
+///
+/// Each loop calls [`register`](ShutdownCoordinator::register) before it
+/// starts and keeps the returned [`ShutdownHandle`] for its whole lifetime,
+/// checking it on every iteration. [`shutdown`](ShutdownCoordinator::shutdown)
+/// signals all handles and then waits until every one of them has been
+/// dropped, i.e. until each loop has finished its teardown (silencing its
+/// buzzer, flushing logs) and exited. Cloning the coordinator is cheap and
+/// yields a handle to the same shutdown.
+///
+/// A loop registered after shutdown has begun sees the signal immediately.
+///
+/// # Example
+/// ```
+/// use env_monitor::shutdown::ShutdownCoordinator;
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let coordinator = ShutdownCoordinator::new();
+///     let stopped = Arc::new(AtomicUsize::new(0));
+///
+///     for _ in 0..3 {
+///         let mut handle = coordinator.register();
+///         let stopped = stopped.clone();
+///         tokio::spawn(async move {
+///             while !handle.is_shutdown() {
+///                 tokio::select! {
+///                     _ = tokio::time::sleep(Duration::from_millis(10)) => {}
+///                     _ = handle.signalled() => {}
+///                 }
+///             }
+///             // Teardown runs before the handle is dropped
+///             tokio::time::sleep(Duration::from_millis(20)).await;
+///             stopped.fetch_add(1, Ordering::SeqCst);
+///         });
+///     }
+///
+///     coordinator.shutdown().await;
+///     assert_eq!(stopped.load(Ordering::SeqCst), 3);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShutdownCoordinator {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    /// Shutdown signal observed by every handle
+    signal: watch::Sender<bool>,
+    /// Cloned into each handle; taken on shutdown so only handles hold senders
+    done_tx: Mutex<Option<mpsc::Sender<()>>>,
+    /// Closes once every handle has been dropped
+    done_rx: tokio::sync::Mutex<mpsc::Receiver<()>>,
+}
+
+/// Registration of one monitoring loop with a [`ShutdownCoordinator`]
+///
+/// Dropping the handle tells the coordinator that the loop has finished.
+#[derive(Debug)]
+pub struct ShutdownHandle {
+    signal: watch::Receiver<bool>,
+    _done: Option<mpsc::Sender<()>>,
+}
+
+impl ShutdownCoordinator {
+    /// Create a new coordinator with no registered loops
+    pub fn new() -> Self {
+        let (signal, _) = watch::channel(false);
+        let (done_tx, done_rx) = mpsc::channel(1);
+        ShutdownCoordinator {
+            inner: Arc::new(Inner {
+                signal,
+                done_tx: Mutex::new(Some(done_tx)),
+                done_rx: tokio::sync::Mutex::new(done_rx),
+            }),
+        }
+    }
+
+    /// Register a monitoring loop
+    ///
+    /// The loop must keep the handle until its teardown is complete.
+    pub fn register(&self) -> ShutdownHandle {
+        ShutdownHandle {
+            signal: self.inner.signal.subscribe(),
+            _done: self.inner.done_tx.lock().unwrap().clone(),
+        }
+    }
+
+    /// Whether shutdown has been requested
+    pub fn is_shutdown(&self) -> bool {
+        *self.inner.signal.borrow()
+    }
+
+    /// Signal all registered loops to stop and wait until they have all exited
+    ///
+    /// Calling this again, or from several clones, is harmless: every call
+    /// returns once all loops have exited.
+    pub async fn shutdown(&self) {
+        self.inner.signal.send_replace(true);
+        self.inner.done_tx.lock().unwrap().take();
+
+        // recv only returns None once every handle's sender has been dropped
+        let mut done_rx = self.inner.done_rx.lock().await;
+        while done_rx.recv().await.is_some() {}
+    }
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownHandle {
+    /// Whether shutdown has been requested
+    ///
+    /// Also true once every clone of the coordinator has been dropped.
+    pub fn is_shutdown(&self) -> bool {
+        *self.signal.borrow() || self.signal.has_changed().is_err()
+    }
+
+    /// Wait until shutdown is requested
+    pub async fn signalled(&mut self) {
+        // An error means the coordinator is gone, which also ends the loop
+        let _ = self.signal.wait_for(|shutdown| *shutdown).await;
+    }
+}
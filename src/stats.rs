@@ -0,0 +1,132 @@
+//! Running min/max/average statistics over a window of readings
+
+use crate::sensors::dht11::Dht11Data;
+
+/// Min/max/average accumulator for temperature and humidity readings
+///
+/// Keeps only running extremes, sums and counts, so summarising a day of
+/// readings takes constant memory. Readings with a missing humidity (NaN) still
+/// count towards the temperature statistics. Call [`reset`](ReadingStats::reset)
+/// to start a new window.
+///
+/// # Example
+/// ```
+/// use env_monitor::stats::ReadingStats;
+/// use env_monitor::Dht11Data;
+///
+/// let mut stats = ReadingStats::new();
+/// assert_eq!(stats.avg_temp(), None);
+///
+/// for (temperature, humidity) in [(20.0, 40.0), (24.0, 60.0), (22.0, f32::NAN)] {
+///     stats.record(&Dht11Data { temperature, humidity, timestamp: 0 });
+/// }
+///
+/// assert_eq!(stats.count(), 3);
+/// assert_eq!((stats.min_temp(), stats.max_temp()), (Some(20.0), Some(24.0)));
+/// assert_eq!(stats.avg_temp(), Some(22.0));
+/// assert_eq!(stats.avg_humidity(), Some(50.0));
+///
+/// stats.reset();
+/// assert_eq!(stats.count(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ReadingStats {
+    /// Temperature statistics
+    temperature: Accumulator,
+    /// Humidity statistics, excluding readings without a humidity value
+    humidity: Accumulator,
+}
+
+impl ReadingStats {
+    /// Create an empty accumulator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a reading to the statistics
+    pub fn record(&mut self, data: &Dht11Data) {
+        self.temperature.record(data.temperature);
+        if !data.humidity.is_nan() {
+            self.humidity.record(data.humidity);
+        }
+    }
+
+    /// Discard all recorded readings to start a new window
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Number of readings recorded since creation or the last reset
+    pub fn count(&self) -> u64 {
+        self.temperature.count
+    }
+
+    /// Lowest temperature recorded (None if no readings)
+    pub fn min_temp(&self) -> Option<f32> {
+        self.temperature.min()
+    }
+
+    /// Highest temperature recorded (None if no readings)
+    pub fn max_temp(&self) -> Option<f32> {
+        self.temperature.max()
+    }
+
+    /// Average temperature recorded (None if no readings)
+    pub fn avg_temp(&self) -> Option<f32> {
+        self.temperature.avg()
+    }
+
+    /// Lowest humidity recorded (None if no readings with a humidity)
+    pub fn min_humidity(&self) -> Option<f32> {
+        self.humidity.min()
+    }
+
+    /// Highest humidity recorded (None if no readings with a humidity)
+    pub fn max_humidity(&self) -> Option<f32> {
+        self.humidity.max()
+    }
+
+    /// Average humidity recorded (None if no readings with a humidity)
+    pub fn avg_humidity(&self) -> Option<f32> {
+        self.humidity.avg()
+    }
+}
+
+// Running extremes, sum and count of a single quantity
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Accumulator {
+    /// Number of values recorded
+    count: u64,
+    /// Sum of the values, in f64 so long windows don't lose precision
+    sum: f64,
+    /// Lowest value (meaningless while count is zero)
+    min: f32,
+    /// Highest value (meaningless while count is zero)
+    max: f32,
+}
+
+impl Accumulator {
+    fn record(&mut self, value: f32) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.count += 1;
+        self.sum += value as f64;
+    }
+
+    fn min(&self) -> Option<f32> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    fn max(&self) -> Option<f32> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    fn avg(&self) -> Option<f32> {
+        (self.count > 0).then(|| (self.sum / self.count as f64) as f32)
+    }
+}
@@ -0,0 +1,265 @@
+//! Replay of scripted flame signals through the fire monitoring loop
+//!
+//! Drives a [`FireSensor`] on a [`MockGpio`] with a script of flame pin levels
+//! and records every event its monitoring loop publishes, with the time since
+//! the start of the replay, so tests can assert the exact sequence of
+//! detections and clears a signal produces under a given debounce, coalesce
+//! gap or check interval. The log also keeps the buzzer writes, giving the
+//! latency from each flame edge to the alarm sounding.
+
+use rppal::gpio::Level;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::sleep_until;
+
+use crate::error::SensorError;
+use crate::events::{EventBus, SensorEvent};
+use crate::sensors::fire::FireSensor;
+use crate::sensors::mock::MockGpio;
+use crate::sensors::traits::FireDetector;
+
+/// Events the replay keeps before the oldest unrecorded ones are dropped
+const EVENT_CAPACITY: usize = 1024;
+
+/// Event published during a replay
+#[derive(Debug, Clone)]
+pub struct RecordedEvent {
+    /// Time since the start of the replay when the event was received
+    pub at: Duration,
+    /// Published event
+    pub event: SensorEvent,
+}
+
+/// Outcome of a replay
+#[derive(Debug, Clone)]
+pub struct ReplayLog {
+    /// Events published by the monitoring loop, in order
+    pub events: Vec<RecordedEvent>,
+    /// Times the script raised a flame on the pin, since the start of the replay
+    pub flame_edges: Vec<Duration>,
+    /// Levels written to the buzzer pin during the replay, with their time
+    pub buzzer_writes: Vec<(Duration, Level)>,
+    /// Level the buzzer pin is held at while silent
+    pub buzzer_idle: Level,
+}
+
+impl ReplayLog {
+    /// Detection state of each flame event, in order (true = detected, false = cleared)
+    ///
+    /// Other events are skipped, which keeps assertions on the sequence short.
+    pub fn flame_states(&self) -> Vec<bool> {
+        self.events
+            .iter()
+            .filter_map(|recorded| match recorded.event {
+                SensorEvent::FlameDetected(_) => Some(true),
+                SensorEvent::FlameCleared(_) => Some(false),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Latency from each flame edge to the first buzzer write sounding the alarm
+    ///
+    /// One entry per entry of `flame_edges`, `None` if the buzzer didn't sound
+    /// after that edge. A polled loop notices an edge at its next check and
+    /// sounds after `debounce_count` agreeing checks, so the latency is at
+    /// most `check_interval_ms * debounce_count` plus scheduling delay.
+    ///
+    /// # Example
+    /// ```
+    /// use env_monitor::sensors::fire::FireSensor;
+    /// use env_monitor::sensors::mock::MockGpio;
+    /// use env_monitor::testing::FireReplay;
+    /// use rppal::gpio::Level;
+    /// use std::time::Duration;
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let ms = Duration::from_millis;
+    /// let (check_interval_ms, debounce_count) = (20, 3);
+    /// let gpio = MockGpio::new();
+    /// let sensor = FireSensor::new(27, 22, true)
+    ///     .with_debounce(debounce_count)
+    ///     .with_alarm_tone(2000, 5)
+    ///     .with_gpio(gpio.clone());
+    ///
+    /// let log = FireReplay::new([(ms(0), Level::Low), (ms(105), Level::High)])
+    ///     .with_check_interval(check_interval_ms)
+    ///     .with_settle(ms(200))
+    ///     .run(sensor, &gpio)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// let latency = log.alarm_latencies()[0].unwrap();
+    /// let bound = ms(check_interval_ms * debounce_count as u64);
+    /// // Allow for the runtime waking the loop late
+    /// assert!(latency <= bound + ms(40), "{:?}", latency);
+    /// # });
+    /// ```
+    pub fn alarm_latencies(&self) -> Vec<Option<Duration>> {
+        self.flame_edges
+            .iter()
+            .map(|&edge| {
+                self.buzzer_writes
+                    .iter()
+                    .find(|&&(at, level)| at >= edge && level != self.buzzer_idle)
+                    .map(|&(at, _)| at - edge)
+            })
+            .collect()
+    }
+}
+
+/// Script of flame pin levels replayed through a monitoring loop
+///
+/// # Example
+/// ```
+/// use env_monitor::events::SensorEvent;
+/// use env_monitor::sensors::fire::FireSensor;
+/// use env_monitor::sensors::mock::MockGpio;
+/// use env_monitor::testing::FireReplay;
+/// use rppal::gpio::Level;
+/// use std::time::Duration;
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let ms = Duration::from_millis;
+/// let gpio = MockGpio::new();
+/// let sensor = FireSensor::new(27, 22, true)
+///     .with_alarm_tone(0, 5)
+///     .with_gpio(gpio.clone());
+///
+/// let log = FireReplay::new([
+///     (ms(0), Level::Low),
+///     (ms(100), Level::High),
+///     (ms(300), Level::Low),
+///     (ms(500), Level::High),
+/// ])
+/// .with_check_interval(10)
+/// .run(sensor, &gpio)
+/// .await
+/// .unwrap();
+///
+/// // Detect, clear, detect, each within a few checks of its edge
+/// assert_eq!(log.flame_states(), [true, false, true]);
+/// for (recorded, edge) in log.events.iter().zip([ms(100), ms(300), ms(500)]) {
+///     assert!(recorded.at >= edge && recorded.at < edge + ms(80), "{:?}", recorded);
+/// }
+/// assert!(matches!(log.events[0].event, SensorEvent::FlameDetected(_)));
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+pub struct FireReplay {
+    /// Flame pin levels, with their time since the start of the replay
+    script: Vec<(Duration, Level)>,
+    /// Check interval of the monitoring loop in milliseconds
+    check_interval_ms: u64,
+    /// Time to keep recording after the last step
+    settle: Duration,
+}
+
+impl FireReplay {
+    /// Create a replay of the given `(time, level)` steps
+    ///
+    /// Steps are applied in time order; levels are the raw pin levels, so
+    /// whether high means flame depends on the sensor's configuration.
+    pub fn new(script: impl IntoIterator<Item = (Duration, Level)>) -> Self {
+        let mut script: Vec<_> = script.into_iter().collect();
+        script.sort_by_key(|&(at, _)| at);
+        FireReplay {
+            script,
+            check_interval_ms: 10,
+            settle: Duration::from_millis(100),
+        }
+    }
+
+    /// Set the check interval the monitoring loop runs at (10ms by default)
+    pub fn with_check_interval(mut self, check_interval_ms: u64) -> Self {
+        self.check_interval_ms = check_interval_ms;
+        self
+    }
+
+    /// Set how long to keep recording after the last step (100ms by default)
+    pub fn with_settle(mut self, settle: Duration) -> Self {
+        self.settle = settle;
+        self
+    }
+
+    /// Run the script through `sensor`'s monitoring loop and return what it published
+    ///
+    /// `sensor` must have been given `gpio` with `with_gpio`; its event bus is
+    /// replaced by the replay's own. Monitoring starts with the first step
+    /// applied and is stopped, with the loop awaited, once the script and the
+    /// settle time are over.
+    pub async fn run(&self, sensor: FireSensor, gpio: &MockGpio) -> Result<ReplayLog, SensorError> {
+        let flame_pin = sensor.flame_pin();
+        let buzzer_pin = sensor.buzzer_pin();
+        let buzzer_idle = sensor.buzzer_idle_level();
+        // Raw level of a flame, read once as the script is replayed against it
+        let flame_level = Level::from(sensor.is_high_active());
+        let mut flame_edges = Vec::new();
+        let bus = EventBus::new(EVENT_CAPACITY);
+        let mut events = bus.subscribe();
+        let sensor = sensor.with_event_bus(bus);
+
+        let mut steps = self.script.iter().peekable();
+        let mut previous = None;
+        if let Some(&&(_, level)) = steps.peek().filter(|(at, _)| at.is_zero()) {
+            gpio.set_level(flame_pin, level);
+            previous = Some(level);
+            steps.next();
+        }
+        if previous == Some(flame_level) {
+            flame_edges.push(Duration::ZERO);
+        }
+
+        let start = Instant::now();
+        let handle = sensor.start_monitoring(self.check_interval_ms).await?;
+
+        // Record until the last sender, held by the sensor and its loop, is gone
+        let recorder = tokio::spawn(async move {
+            let mut recorded = Vec::new();
+            loop {
+                match events.recv().await {
+                    Ok(event) => recorded.push(RecordedEvent {
+                        at: start.elapsed(),
+                        event,
+                    }),
+                    Err(RecvError::Lagged(skipped)) => {
+                        log::warn!("Replay dropped {} events", skipped)
+                    }
+                    Err(RecvError::Closed) => break recorded,
+                }
+            }
+        });
+
+        for &(at, level) in steps {
+            sleep_until((start + at).into()).await;
+            let applied = start.elapsed();
+            gpio.set_level(flame_pin, level);
+            if level == flame_level && previous != Some(level) {
+                flame_edges.push(applied);
+            }
+            previous = Some(level);
+        }
+        let end = self.script.last().map_or(Duration::ZERO, |&(at, _)| at) + self.settle;
+        sleep_until((start + end).into()).await;
+
+        sensor.stop_monitoring();
+        let _ = handle.await;
+        drop(sensor);
+
+        let events = recorder
+            .await
+            .map_err(|e| SensorError::SensorError(format!("Task join error: {}", e)))?;
+        let buzzer_writes = gpio
+            .writes(buzzer_pin)
+            .into_iter()
+            .filter(|&(time, _)| time >= start)
+            .map(|(time, level)| (time - start, level))
+            .collect();
+        Ok(ReplayLog {
+            events,
+            flame_edges,
+            buzzer_writes,
+            buzzer_idle,
+        })
+    }
+}
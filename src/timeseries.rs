@@ -0,0 +1,143 @@
+//! Bounded in-memory time-series buffer with downsampling
+
+use std::collections::VecDeque;
+
+/// Single point of a time series, possibly averaged from several readings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// Timestamp of the earliest reading in this point (Unix seconds)
+    pub timestamp: u64,
+    /// Average value of the readings in this point
+    pub value: f32,
+    /// Number of readings averaged into this point
+    pub count: u32,
+}
+
+/// Bounded time-series buffer that downsamples older data as it fills
+///
+/// The buffer never holds more than `capacity` points. When a new reading
+/// arrives at a full buffer, the older half of the points is downsampled by
+/// averaging adjacent pairs (weighted by how many readings each holds) into
+/// one point stamped with the earlier timestamp. Recent data therefore keeps
+/// full resolution while older data becomes progressively coarser, and the
+/// whole history stays within a fixed memory budget.
+///
+/// # Example
+/// ```
+/// use env_monitor::timeseries::TimeSeriesBuffer;
+///
+/// let mut buffer = TimeSeriesBuffer::new(4);
+/// for (minute, temperature) in [20.0, 22.0, 24.0, 26.0, 28.0].into_iter().enumerate() {
+///     buffer.push(minute as u64 * 60, temperature);
+/// }
+///
+/// // The two oldest points were averaged to make room
+/// assert_eq!(buffer.len(), 4);
+/// let oldest = buffer.range(0, 59)[0];
+/// assert_eq!((oldest.timestamp, oldest.value, oldest.count), (0, 21.0, 2));
+///
+/// // Recent points keep their original resolution
+/// let recent = buffer.range(180, 240);
+/// assert_eq!(recent.iter().map(|s| s.value).collect::<Vec<_>>(), vec![26.0, 28.0]);
+///
+/// // Even the smallest buffer stays within its capacity
+/// let mut buffer = TimeSeriesBuffer::new(2);
+/// for minute in 0..100 {
+///     buffer.push(minute * 60, 20.0);
+///     assert!(buffer.len() <= buffer.capacity());
+/// }
+/// let readings: u32 = buffer.range(0, u64::MAX).iter().map(|s| s.count).sum();
+/// assert_eq!(readings, 100);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TimeSeriesBuffer {
+    /// Maximum number of points held
+    capacity: usize,
+    /// Points ordered by timestamp
+    points: VecDeque<Sample>,
+}
+
+impl TimeSeriesBuffer {
+    /// Create a new buffer holding at most `capacity` points (minimum 2)
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(2);
+        TimeSeriesBuffer {
+            capacity,
+            points: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Record a reading, downsampling older points if the buffer is full
+    ///
+    /// Readings are kept ordered by timestamp; an out-of-order reading is
+    /// inserted at its position.
+    pub fn push(&mut self, timestamp: u64, value: f32) {
+        if self.points.len() >= self.capacity {
+            self.downsample();
+        }
+
+        let index = self.points.partition_point(|s| s.timestamp <= timestamp);
+        self.points.insert(
+            index,
+            Sample {
+                timestamp,
+                value,
+                count: 1,
+            },
+        );
+    }
+
+    /// Points with timestamps within `start..=end`, oldest first
+    pub fn range(&self, start: u64, end: u64) -> Vec<Sample> {
+        self.points
+            .iter()
+            .filter(|s| s.timestamp >= start && s.timestamp <= end)
+            .copied()
+            .collect()
+    }
+
+    /// Number of points currently held
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Whether the buffer holds no points
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Maximum number of points held
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    // Average adjacent pairs in the older half of the buffer
+    //
+    // The older half covers at least two points, so every pass frees a slot
+    // even when a small capacity makes the half a single point.
+    fn downsample(&mut self) {
+        let older = (self.points.len() / 2).max(2);
+        let mut merged = VecDeque::with_capacity(self.capacity);
+
+        let mut i = 0;
+        while i < older {
+            let first = self.points[i];
+            if i + 1 < older {
+                let second = self.points[i + 1];
+                let count = first.count + second.count;
+                merged.push_back(Sample {
+                    timestamp: first.timestamp,
+                    value: (first.value * first.count as f32 + second.value * second.count as f32)
+                        / count as f32,
+                    count,
+                });
+            } else {
+                merged.push_back(first);
+            }
+            i += 2;
+        }
+
+        merged.extend(self.points.drain(older..));
+        self.points = merged;
+    }
+}